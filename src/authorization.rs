@@ -1,3 +1,4 @@
+use biscuit_auth::builder::PolicyKind as UpstreamPolicyKind;
 use biscuit_auth::error::{
     FailedAuthorizerCheck, FailedBlockCheck, FailedCheck as UpstreamFailedCheck,
     MatchedPolicy as UpstreamMatchedPolicy,
@@ -138,6 +139,176 @@ impl FailedCheck {
     }
 }
 
+#[php_class]
+#[php(name = "Biscuit\\Auth\\CheckResult")]
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    origin: CheckOrigin,
+    block_id: Option<i64>,
+    check_id: i64,
+    rule: String,
+    passed: bool,
+}
+
+#[php_impl]
+impl CheckResult {
+    pub fn get_origin(&self) -> String {
+        self.origin.as_str().to_string()
+    }
+
+    pub fn get_block_id(&self) -> Option<i64> {
+        self.block_id
+    }
+
+    pub fn get_check_id(&self) -> i64 {
+        self.check_id
+    }
+
+    pub fn get_rule(&self) -> String {
+        self.rule.clone()
+    }
+
+    pub fn has_passed(&self) -> bool {
+        self.passed
+    }
+}
+
+#[php_class]
+#[php(name = "Biscuit\\Auth\\PolicyResult")]
+#[derive(Debug, Clone)]
+pub struct PolicyResult {
+    kind: PolicyKind,
+    policy_id: i64,
+    code: String,
+    matched: Option<bool>,
+}
+
+#[php_impl]
+impl PolicyResult {
+    pub fn get_kind(&self) -> String {
+        self.kind.as_str().to_string()
+    }
+
+    pub fn get_policy_id(&self) -> i64 {
+        self.policy_id
+    }
+
+    pub fn get_code(&self) -> String {
+        self.code.clone()
+    }
+
+    pub fn is_matched(&self) -> Option<bool> {
+        self.matched
+    }
+}
+
+#[php_class]
+#[php(name = "Biscuit\\Auth\\AuthorizationResult")]
+#[derive(Debug, Clone)]
+pub struct AuthorizationResult {
+    decision: PolicyKind,
+    matched_policy: Option<MatchedPolicy>,
+    checks: Vec<CheckResult>,
+    failed_checks: Vec<FailedCheck>,
+    policies: Vec<PolicyResult>,
+}
+
+impl AuthorizationResult {
+    pub(crate) fn new(
+        matched_policy: Option<&UpstreamMatchedPolicy>,
+        failed_checks: &[UpstreamFailedCheck],
+        checks: Vec<(Option<i64>, i64, String)>,
+        policies: &[biscuit_auth::builder::Policy],
+    ) -> Self {
+        let failed_checks: Vec<FailedCheck> = failed_checks
+            .iter()
+            .map(FailedCheck::from_upstream)
+            .collect();
+
+        let checks = checks
+            .into_iter()
+            .map(|(block_id, check_id, rule)| {
+                let passed = !failed_checks
+                    .iter()
+                    .any(|f| f.block_id == block_id && f.check_id == check_id);
+                CheckResult {
+                    origin: match block_id {
+                        Some(_) => CheckOrigin::Block,
+                        None => CheckOrigin::Authorizer,
+                    },
+                    block_id,
+                    check_id,
+                    rule,
+                    passed,
+                }
+            })
+            .collect();
+
+        let matched_id = matched_policy.map(|policy| match policy {
+            UpstreamMatchedPolicy::Allow(i) | UpstreamMatchedPolicy::Deny(i) => *i,
+        });
+        let policy_results = policies
+            .iter()
+            .enumerate()
+            .map(|(idx, policy)| PolicyResult {
+                kind: match policy.kind {
+                    UpstreamPolicyKind::Allow => PolicyKind::Allow,
+                    UpstreamPolicyKind::Deny => PolicyKind::Deny,
+                },
+                policy_id: idx as i64,
+                code: policy.to_string(),
+                matched: match matched_id {
+                    Some(m) if idx > m => None,
+                    Some(m) => Some(idx == m),
+                    None => Some(false),
+                },
+            })
+            .collect();
+
+        let allowed = matches!(matched_policy, Some(UpstreamMatchedPolicy::Allow(_)))
+            && failed_checks.is_empty();
+
+        Self {
+            decision: if allowed {
+                PolicyKind::Allow
+            } else {
+                PolicyKind::Deny
+            },
+            matched_policy: matched_policy.map(|p| MatchedPolicy::from_upstream(p, policies)),
+            checks,
+            failed_checks,
+            policies: policy_results,
+        }
+    }
+}
+
+#[php_impl]
+impl AuthorizationResult {
+    pub fn get_decision(&self) -> String {
+        self.decision.as_str().to_string()
+    }
+
+    pub fn is_allowed(&self) -> bool {
+        matches!(self.decision, PolicyKind::Allow)
+    }
+
+    pub fn get_matched_policy(&self) -> Option<MatchedPolicy> {
+        self.matched_policy.clone()
+    }
+
+    pub fn get_checks(&self) -> Vec<CheckResult> {
+        self.checks.clone()
+    }
+
+    pub fn get_failed_checks(&self) -> Vec<FailedCheck> {
+        self.failed_checks.clone()
+    }
+
+    pub fn get_policies(&self) -> Vec<PolicyResult> {
+        self.policies.clone()
+    }
+}
+
 #[php_class]
 #[php(name = "Biscuit\\Exception\\AuthorizationException")]
 #[php(extends(BiscuitException))]
@@ -1,15 +1,24 @@
 use std::collections::HashMap;
 
+use biscuit_auth::error::{Logic, MatchedPolicy as UpstreamMatchedPolicy, Token};
+use biscuit_auth::format::schema;
+use ext_php_rs::binary::Binary;
 use ext_php_rs::binary_slice::BinarySlice;
+use ext_php_rs::boxed::ZBox;
+use ext_php_rs::builders::ClassBuilder;
 use ext_php_rs::prelude::*;
+use ext_php_rs::types::ZendHashTable;
+use prost::Message;
 
-use crate::authorization::MatchedPolicy;
+use crate::authorization::{AuthorizationResult, MatchedPolicy};
 use crate::biscuit::Biscuit;
 use crate::builders::BlockBuilder;
-use crate::datalog::{Check, Fact, Policy, Rule};
-use crate::errors::{BiscuitError, BuildKind, DatalogKind, FormatKind, ResultExt};
+use crate::datalog::{Check, Fact, Origin, Policy, Rule};
+use crate::errors::{BiscuitError, BuildKind, DatalogKind, FormatKind, ResultExt, RunResultExt};
 use crate::helpers::{
-    MixedValue, collect_scope_params, collect_term_params, get_builder, take_builder,
+    DateValue, MixedValue, Unserialize, collect_scope_params, collect_term_params, decode_checks,
+    decode_facts, decode_rules, get_builder, run_limits, serialized_field, symbol_table,
+    take_builder, with_unserialize,
 };
 use crate::keys::PublicKey;
 
@@ -18,27 +27,212 @@ use crate::keys::PublicKey;
 #[derive(Clone)]
 pub struct Authorizer(biscuit_auth::Authorizer);
 
-#[php_impl]
 impl Authorizer {
-    pub fn authorize(&mut self) -> PhpResult<MatchedPolicy> {
-        let (_, _, _, policies) = self.0.dump();
-        match self.0.authorize() {
+    fn matched_policy(
+        result: Result<usize, biscuit_auth::error::Token>,
+        policies: Vec<biscuit_auth::builder::Policy>,
+    ) -> PhpResult<MatchedPolicy> {
+        match result {
             Ok(idx) => {
                 let code = policies.get(idx).map(ToString::to_string);
                 Ok(MatchedPolicy::allow(idx, code))
             }
-            Err(err) => Err(BiscuitError::Authorization {
-                source: err,
-                policies,
-            }
-            .into()),
+            Err(err) => Err(BiscuitError::from_authorization(err, policies).into()),
         }
     }
 
+    fn world(&self) -> PhpResult<World> {
+        let bytes = self.0.to_raw_snapshot().format(FormatKind::Snapshot)?;
+        let snapshot =
+            schema::AuthorizerSnapshot::decode(bytes.as_slice()).format(FormatKind::Snapshot)?;
+        World::decode(snapshot.world)
+    }
+
+    fn check_ids(&self) -> PhpResult<Vec<(Option<i64>, i64, String)>> {
+        let mut next_ids: HashMap<Option<i64>, i64> = HashMap::new();
+        Ok(self
+            .world()?
+            .checks
+            .into_iter()
+            .map(|(check, origin)| {
+                let block_id = match origin {
+                    Origin::Block(id) => Some(id),
+                    Origin::Authorizer => None,
+                };
+                let check_id = next_ids.entry(block_id).or_default();
+                let ids = (block_id, *check_id, check.to_string());
+                *check_id += 1;
+                ids
+            })
+            .collect())
+    }
+}
+
+/// Authorizer contents decoded from its snapshot, keeping track of the
+/// block each fact, rule and check comes from.
+struct World {
+    facts: Vec<(biscuit_auth::builder::Fact, Vec<Origin>)>,
+    rules: Vec<(biscuit_auth::builder::Rule, Origin)>,
+    checks: Vec<(biscuit_auth::builder::Check, Origin)>,
+}
+
+impl World {
+    fn decode(world: schema::AuthorizerWorld) -> PhpResult<Self> {
+        let symbols =
+            symbol_table(world.symbols, &world.public_keys).format(FormatKind::Snapshot)?;
+
+        let blocks = world.blocks.iter().enumerate();
+        let blocks = std::iter::once((Origin::Authorizer, &world.authorizer_block))
+            .chain(blocks.map(|(i, block)| (Origin::Block(i as i64), block)));
+
+        let mut rules = Vec::new();
+        let mut checks = Vec::new();
+        for (origin, block) in blocks {
+            let version = block.version.or(world.version).unwrap_or_default();
+            let block_rules =
+                decode_rules(&block.rules, version, &symbols).format(FormatKind::Snapshot)?;
+            rules.extend(block_rules.into_iter().map(|rule| (rule, origin)));
+            let block_checks =
+                decode_checks(&block.checks, version, &symbols).format(FormatKind::Snapshot)?;
+            checks.extend(block_checks.into_iter().map(|check| (check, origin)));
+        }
+
+        let mut facts = Vec::new();
+        for generated in &world.generated_facts {
+            let origins: Vec<Origin> = generated.origins.iter().map(Origin::from).collect();
+            let generated_facts =
+                decode_facts(&generated.facts, &symbols).format(FormatKind::Snapshot)?;
+            facts.extend(
+                generated_facts
+                    .into_iter()
+                    .map(|fact| (fact, origins.clone())),
+            );
+        }
+
+        Ok(Self {
+            facts,
+            rules,
+            checks,
+        })
+    }
+}
+
+#[php_impl]
+impl Authorizer {
+    pub fn authorize(&mut self) -> PhpResult<MatchedPolicy> {
+        let (_, _, _, policies) = self.0.dump();
+        let result = self.0.authorize();
+        Self::matched_policy(result, policies)
+    }
+
+    pub fn authorize_with_limits(
+        &mut self,
+        max_facts: u64,
+        max_iterations: u64,
+        max_time_micros: u64,
+    ) -> PhpResult<MatchedPolicy> {
+        let (_, _, _, policies) = self.0.dump();
+        let limits = run_limits(max_facts, max_iterations, max_time_micros);
+        let result = self.0.authorize_with_limits(limits);
+        Self::matched_policy(result, policies)
+    }
+
+    pub fn evaluate(&mut self) -> PhpResult<AuthorizationResult> {
+        let (_, _, _, policies) = self.0.dump();
+        let checks = self.check_ids()?;
+        let (matched, failed) = match self.0.authorize() {
+            Ok(idx) => (Some(UpstreamMatchedPolicy::Allow(idx)), Vec::new()),
+            Err(Token::FailedLogic(Logic::Unauthorized { policy, checks })) => {
+                (Some(policy), checks)
+            }
+            Err(Token::FailedLogic(Logic::NoMatchingPolicy { checks })) => (None, checks),
+            Err(err) => return Err(BiscuitError::from_authorization(err, policies).into()),
+        };
+        Ok(AuthorizationResult::new(
+            matched.as_ref(),
+            &failed,
+            checks,
+            &policies,
+        ))
+    }
+
     pub fn query(&mut self, rule: &Rule) -> PhpResult<Vec<Fact>> {
         let facts: Vec<biscuit_auth::builder::Fact> =
-            self.0.query(rule.0.clone()).build(BuildKind::Authorizer)?;
-        Ok(facts.into_iter().map(Fact).collect())
+            self.0.query(rule.0.clone()).run(BuildKind::Authorizer)?;
+        Ok(facts.into_iter().map(|f| Fact(f, None)).collect())
+    }
+
+    pub fn query_with_limits(
+        &mut self,
+        rule: &Rule,
+        max_facts: u64,
+        max_iterations: u64,
+        max_time_micros: u64,
+    ) -> PhpResult<Vec<Fact>> {
+        let limits = run_limits(max_facts, max_iterations, max_time_micros);
+        let facts: Vec<biscuit_auth::builder::Fact> = self
+            .0
+            .query_with_limits(rule.0.clone(), limits)
+            .run(BuildKind::Authorizer)?;
+        Ok(facts.into_iter().map(|f| Fact(f, None)).collect())
+    }
+
+    pub fn query_all(&mut self, rule: &Rule) -> PhpResult<Vec<Fact>> {
+        let facts: Vec<biscuit_auth::builder::Fact> = self
+            .0
+            .query_all(rule.0.clone())
+            .run(BuildKind::Authorizer)?;
+        Ok(facts.into_iter().map(|f| Fact(f, None)).collect())
+    }
+
+    pub fn query_all_with_limits(
+        &mut self,
+        rule: &Rule,
+        max_facts: u64,
+        max_iterations: u64,
+        max_time_micros: u64,
+    ) -> PhpResult<Vec<Fact>> {
+        let limits = run_limits(max_facts, max_iterations, max_time_micros);
+        let facts: Vec<biscuit_auth::builder::Fact> = self
+            .0
+            .query_all_with_limits(rule.0.clone(), limits)
+            .run(BuildKind::Authorizer)?;
+        Ok(facts.into_iter().map(|f| Fact(f, None)).collect())
+    }
+
+    pub fn get_facts(&self) -> PhpResult<Vec<Fact>> {
+        Ok(self
+            .world()?
+            .facts
+            .into_iter()
+            .map(|(f, origins)| Fact(f, Some(origins)))
+            .collect())
+    }
+
+    pub fn get_rules(&self) -> PhpResult<Vec<Rule>> {
+        Ok(self
+            .world()?
+            .rules
+            .into_iter()
+            .map(|(r, origin)| Rule(r, Some(vec![origin])))
+            .collect())
+    }
+
+    pub fn get_checks(&self) -> PhpResult<Vec<Check>> {
+        Ok(self
+            .world()?
+            .checks
+            .into_iter()
+            .map(|(c, origin)| Check(c, Some(vec![origin])))
+            .collect())
+    }
+
+    pub fn get_policies(&self) -> Vec<Policy> {
+        let (_, _, _, policies) = self.0.dump();
+        policies
+            .into_iter()
+            .map(|p| Policy(p, Some(vec![Origin::Authorizer])))
+            .collect()
     }
 
     pub fn base64_snapshot(&self) -> PhpResult<String> {
@@ -71,6 +265,7 @@ impl Authorizer {
 
 #[php_class]
 #[php(name = "Biscuit\\Auth\\AuthorizerBuilder")]
+#[php(modifier = authorizer_builder_unserialize)]
 #[derive(Clone)]
 pub struct AuthorizerBuilder(Option<biscuit_auth::AuthorizerBuilder>);
 
@@ -136,8 +331,32 @@ impl AuthorizerBuilder {
         Ok(())
     }
 
-    pub fn set_time(&mut self) -> PhpResult<()> {
-        self.0 = Some(take_builder(&mut self.0)?.time());
+    pub fn set_time(&mut self, at: Option<DateValue>) -> PhpResult<()> {
+        let builder = take_builder(&mut self.0)?;
+        let next = match at {
+            None => builder.time(),
+            Some(at) => {
+                let params = HashMap::from([(
+                    "time".to_string(),
+                    biscuit_auth::builder::Term::Date(at.timestamp()),
+                )]);
+                builder
+                    .code_with_params("time({time})", params, HashMap::new())
+                    .datalog(DatalogKind::Fact)?
+            }
+        };
+        self.0 = Some(next);
+        Ok(())
+    }
+
+    pub fn set_limits(
+        &mut self,
+        max_facts: u64,
+        max_iterations: u64,
+        max_time_micros: u64,
+    ) -> PhpResult<()> {
+        let limits = run_limits(max_facts, max_iterations, max_time_micros);
+        self.0 = Some(take_builder(&mut self.0)?.set_limits(limits));
         Ok(())
     }
 
@@ -179,11 +398,17 @@ impl AuthorizerBuilder {
         Ok(Self(Some(builder)))
     }
 
+    pub fn __serialize(&self) -> PhpResult<ZBox<ZendHashTable>> {
+        let mut data = ZendHashTable::new();
+        data.insert("snapshot", Binary::from(self.raw_snapshot()?))?;
+        Ok(data)
+    }
+
     pub fn build(&self, token: &Biscuit) -> PhpResult<Authorizer> {
         let authorizer = get_builder(&self.0)?
             .clone()
             .build(&token.0)
-            .build(BuildKind::Authorizer)?;
+            .run(BuildKind::Authorizer)?;
         Ok(Authorizer(authorizer))
     }
 
@@ -191,7 +416,7 @@ impl AuthorizerBuilder {
         let authorizer = get_builder(&self.0)?
             .clone()
             .build_unauthenticated()
-            .build(BuildKind::Authorizer)?;
+            .run(BuildKind::Authorizer)?;
         Ok(Authorizer(authorizer))
     }
 
@@ -199,3 +424,15 @@ impl AuthorizerBuilder {
         Ok(get_builder(&self.0)?.to_string())
     }
 }
+
+impl Unserialize for AuthorizerBuilder {
+    fn unserialize(data: &ZendHashTable) -> PhpResult<Self> {
+        let snapshot: BinarySlice<u8> =
+            serialized_field(data, "snapshot").format(FormatKind::Snapshot)?;
+        Self::from_raw_snapshot(snapshot)
+    }
+}
+
+fn authorizer_builder_unserialize(builder: ClassBuilder) -> ClassBuilder {
+    with_unserialize::<AuthorizerBuilder>(builder)
+}
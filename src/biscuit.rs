@@ -1,16 +1,85 @@
+use std::cell::{Cell, RefCell};
+
+use biscuit_auth::RootKeyProvider;
+use biscuit_auth::error::{Format, Token};
+use biscuit_auth::format::schema;
+use ext_php_rs::binary::Binary;
 use ext_php_rs::binary_slice::BinarySlice;
+use ext_php_rs::boxed::ZBox;
+use ext_php_rs::builders::ClassBuilder;
 use ext_php_rs::prelude::*;
+use ext_php_rs::types::ZendHashTable;
+use prost::Message;
 
 use crate::builders::{BiscuitBuilder, BlockBuilder};
-use crate::errors::{BuildKind, FormatKind, ResultExt};
-use crate::helpers::get_builder;
-use crate::keys::PublicKey;
+use crate::datalog::{Check, Fact, Origin, Rule};
+use crate::errors::{BuildKind, FormatKind, KeyKind, ResultExt, StaticError};
+use crate::helpers::{
+    Unserialize, decode_checks, decode_facts, decode_rules, get_builder, serialized_field,
+    symbol_table, with_unserialize,
+};
+use crate::keys::{Algorithm, KeyRing, PublicKey, RootKey};
 use crate::third_party::{ThirdPartyBlock, ThirdPartyRequest};
 
+/// Root key set with `Biscuit::setUnserializeRootKey()`, owned so it outlives
+/// the call that set it.
+#[derive(Clone)]
+enum UnserializeRoot {
+    Key(biscuit_auth::PublicKey),
+    Ring(KeyRing),
+}
+
+thread_local! {
+    static UNSERIALIZE_ROOT: RefCell<Option<UnserializeRoot>> = const { RefCell::new(None) };
+}
+
+pub(crate) fn reset_unserialize_root_key() {
+    UNSERIALIZE_ROOT.set(None);
+}
+
+/// Sealed tokens end with a final signature instead of the secret key for
+/// the next block.
+fn is_sealed(token: &[u8]) -> PhpResult<bool> {
+    let proto = schema::Biscuit::decode(token).format(FormatKind::Bytes)?;
+    Ok(matches!(
+        proto.proof.content,
+        Some(schema::proof::Content::FinalSignature(_))
+    ))
+}
+
 #[php_class]
 #[php(name = "Biscuit\\Auth\\Biscuit")]
+#[php(modifier = biscuit_unserialize)]
 #[derive(Clone)]
-pub struct Biscuit(pub(crate) biscuit_auth::Biscuit);
+pub struct Biscuit(
+    pub(crate) biscuit_auth::Biscuit,
+    pub(crate) biscuit_auth::PublicKey,
+);
+
+impl Biscuit {
+    /// Verifies a token against `root`, keeping the root key that matched
+    /// since the token itself does not carry it.
+    fn verify_with(
+        root: &RootKey,
+        kind: FormatKind,
+        verify: impl FnOnce(
+            &dyn Fn(Option<u32>) -> Result<biscuit_auth::PublicKey, Format>,
+        ) -> Result<biscuit_auth::Biscuit, Token>,
+    ) -> PhpResult<Self> {
+        let root_key = Cell::new(None);
+        let token = verify(&|id| {
+            let key = root.choose(id)?;
+            root_key.set(Some(key));
+            Ok(key)
+        });
+        let token = root.verified(token, kind)?;
+        let root_key = root_key
+            .get()
+            .ok_or(StaticError("no root key was used to verify the token"))
+            .format(kind)?;
+        Ok(Self(token, root_key))
+    }
+}
 
 #[php_impl]
 impl Biscuit {
@@ -20,17 +89,17 @@ impl Biscuit {
     }
 
     #[php(name = "fromBytes")]
-    pub fn from_bytes(data: BinarySlice<u8>, root: &PublicKey) -> PhpResult<Self> {
-        Ok(Self(
-            biscuit_auth::Biscuit::from(data.as_ref(), root.0).format(FormatKind::Bytes)?,
-        ))
+    pub fn from_bytes(data: BinarySlice<u8>, root: RootKey) -> PhpResult<Self> {
+        Self::verify_with(&root, FormatKind::Bytes, |root| {
+            biscuit_auth::Biscuit::from(data.as_ref(), root)
+        })
     }
 
     #[php(name = "fromBase64")]
-    pub fn from_base64(data: &str, root: &PublicKey) -> PhpResult<Self> {
-        Ok(Self(
-            biscuit_auth::Biscuit::from_base64(data, root.0).format(FormatKind::Base64)?,
-        ))
+    pub fn from_base64(data: &str, root: RootKey) -> PhpResult<Self> {
+        Self::verify_with(&root, FormatKind::Base64, |root| {
+            biscuit_auth::Biscuit::from_base64(data, root)
+        })
     }
 
     pub fn to_bytes(&self) -> PhpResult<Vec<u8>> {
@@ -57,6 +126,7 @@ impl Biscuit {
             self.0
                 .append(get_builder(&block.0)?.clone())
                 .build(BuildKind::Append)?,
+            self.1,
         ))
     }
 
@@ -65,16 +135,33 @@ impl Biscuit {
         external_key: &PublicKey,
         block: &ThirdPartyBlock,
     ) -> PhpResult<Self> {
-        Ok(Self(
-            self.0
-                .append_third_party(external_key.0, block.0.clone())
-                .build(BuildKind::ThirdPartyAppend)?,
-        ))
+        // Deserialized blocks can only be appended as bytes, which requires
+        // an unverified token; the result is verified again with the root key.
+        let bytes = block.signed_by(external_key)?;
+        let result = self
+            .0
+            .to_vec()
+            .and_then(biscuit_auth::UnverifiedBiscuit::from)
+            .and_then(|token| token.append_third_party(&bytes))
+            .and_then(|token| token.verify(self.1).map_err(Token::Format));
+        let kind = match result {
+            Err(Token::AppendOnSealed) => BuildKind::Append,
+            _ => BuildKind::ThirdPartyAppend,
+        };
+        Ok(Self(result.build(kind)?, self.1))
+    }
+
+    pub fn seal(&self) -> PhpResult<Self> {
+        Ok(Self(self.0.seal().build(BuildKind::Token)?, self.1))
+    }
+
+    pub fn is_sealed(&self) -> PhpResult<bool> {
+        is_sealed(&self.0.to_vec().format(FormatKind::Bytes)?)
     }
 
     pub fn third_party_request(&self) -> PhpResult<ThirdPartyRequest> {
         let request = self.0.third_party_request().third_party()?;
-        Ok(ThirdPartyRequest(Some(request)))
+        Ok(ThirdPartyRequest(request))
     }
 
     pub fn revocation_ids(&self) -> Vec<String> {
@@ -93,18 +180,219 @@ impl Biscuit {
         Ok(key.map(PublicKey))
     }
 
+    pub fn block_context(&self, index: i64) -> PhpResult<Option<String>> {
+        let context = usize::try_from(index)
+            .ok()
+            .and_then(|i| self.0.context().get(i).cloned())
+            .ok_or(StaticError("block index out of range"))
+            .format(FormatKind::Snapshot)?;
+        Ok(context)
+    }
+
+    pub fn get_block(&self, index: i64) -> PhpResult<Block> {
+        Block::new(&self.0, self.1, index)
+    }
+
     pub fn __to_string(&self) -> String {
         self.0.print()
     }
+
+    #[php(name = "setUnserializeRootKey")]
+    pub fn set_unserialize_root_key(root: Option<RootKey>) -> PhpResult<()> {
+        let root = match root {
+            Some(RootKey::Key(key)) => Some(UnserializeRoot::Key(key)),
+            Some(RootKey::Ring(ring)) => Some(UnserializeRoot::Ring(ring.clone())),
+            Some(RootKey::Provider(..)) => Err::<_, _>(StaticError(
+                "unserialize() needs a PublicKey or KeyRing, not a callable",
+            ))
+            .key(KeyKind::KeyRing)?,
+            None => None,
+        };
+        UNSERIALIZE_ROOT.set(root);
+        Ok(())
+    }
+
+    pub fn __serialize(&self) -> PhpResult<ZBox<ZendHashTable>> {
+        let mut data = ZendHashTable::new();
+        data.insert("token", Binary::from(self.to_bytes()?))?;
+        Ok(data)
+    }
+}
+
+impl Unserialize for Biscuit {
+    fn unserialize(data: &ZendHashTable) -> PhpResult<Self> {
+        let token: BinarySlice<u8> = serialized_field(data, "token").format(FormatKind::Bytes)?;
+        let root = UNSERIALIZE_ROOT
+            .with_borrow(Clone::clone)
+            .ok_or(StaticError(
+                "no root key to verify the token, call Biscuit::setUnserializeRootKey() first",
+            ))
+            .key(KeyKind::KeyRing)?;
+        match &root {
+            UnserializeRoot::Key(key) => Self::from_bytes(token, RootKey::Key(*key)),
+            UnserializeRoot::Ring(ring) => Self::from_bytes(token, RootKey::Ring(ring)),
+        }
+    }
+}
+
+fn biscuit_unserialize(builder: ClassBuilder) -> ClassBuilder {
+    with_unserialize::<Biscuit>(builder)
+}
+
+#[php_class]
+#[php(name = "Biscuit\\Auth\\Block")]
+#[derive(Debug, Clone)]
+pub struct Block {
+    index: i64,
+    facts: Vec<Fact>,
+    rules: Vec<Rule>,
+    checks: Vec<Check>,
+    context: Option<String>,
+    version: u32,
+    external_key: Option<PublicKey>,
+    algorithm: Algorithm,
+    revocation_id: String,
+}
+
+impl Block {
+    fn new(
+        token: &biscuit_auth::Biscuit,
+        root: biscuit_auth::PublicKey,
+        index: i64,
+    ) -> PhpResult<Self> {
+        let position = usize::try_from(index)
+            .ok()
+            .filter(|i| *i < token.block_count())
+            .ok_or(StaticError("block index out of range"))
+            .format(FormatKind::Snapshot)?;
+
+        let bytes = token.to_vec().format(FormatKind::Bytes)?;
+        let proto = schema::Biscuit::decode(bytes.as_slice()).format(FormatKind::Bytes)?;
+        let signed: Vec<_> = std::iter::once(&proto.authority)
+            .chain(&proto.blocks)
+            .collect();
+        let block = signed
+            .get(position)
+            .ok_or(StaticError("block index out of range"))
+            .format(FormatKind::Snapshot)?;
+
+        // First-party blocks share the token's symbol table, built up block
+        // by block; third-party blocks only use their own.
+        let mut symbols = Vec::new();
+        let mut public_keys = Vec::new();
+        let mut contents = None;
+        for signed_block in &signed[..=position] {
+            let decoded =
+                schema::Block::decode(signed_block.block.as_slice()).format(FormatKind::Bytes)?;
+            if signed_block.external_signature.is_none() {
+                symbols.extend(decoded.symbols.iter().cloned());
+                public_keys.extend(decoded.public_keys.iter().cloned());
+            }
+            contents = Some(decoded);
+        }
+        let contents = contents
+            .ok_or(StaticError("block index out of range"))
+            .format(FormatKind::Snapshot)?;
+        let symbols = if block.external_signature.is_some() {
+            symbol_table(contents.symbols.clone(), &contents.public_keys)
+        } else {
+            symbol_table(symbols, &public_keys)
+        }
+        .format(FormatKind::Bytes)?;
+        let version = contents.version.unwrap_or_default();
+        let origins = Some(vec![Origin::Block(index)]);
+        // Each block is signed by the key generated with the previous one,
+        // the authority block by the root key.
+        let signer = match position.checked_sub(1).and_then(|i| signed.get(i)) {
+            Some(previous) => {
+                biscuit_auth::PublicKey::from_proto(&previous.next_key).format(FormatKind::Bytes)?
+            }
+            None => root,
+        };
+
+        Ok(Self {
+            index,
+            facts: decode_facts(&contents.facts, &symbols)
+                .format(FormatKind::Bytes)?
+                .into_iter()
+                .map(|fact| Fact(fact, origins.clone()))
+                .collect(),
+            rules: decode_rules(&contents.rules, version, &symbols)
+                .format(FormatKind::Bytes)?
+                .into_iter()
+                .map(|rule| Rule(rule, origins.clone()))
+                .collect(),
+            checks: decode_checks(&contents.checks, version, &symbols)
+                .format(FormatKind::Bytes)?
+                .into_iter()
+                .map(|check| Check(check, origins.clone()))
+                .collect(),
+            context: contents.context,
+            version,
+            external_key: token
+                .block_external_key(position)
+                .format(FormatKind::Snapshot)?
+                .map(PublicKey),
+            algorithm: signer.algorithm().into(),
+            revocation_id: hex::encode(&block.signature),
+        })
+    }
+}
+
+#[php_impl]
+impl Block {
+    pub fn get_index(&self) -> i64 {
+        self.index
+    }
+
+    pub fn get_facts(&self) -> Vec<Fact> {
+        self.facts.clone()
+    }
+
+    pub fn get_rules(&self) -> Vec<Rule> {
+        self.rules.clone()
+    }
+
+    pub fn get_checks(&self) -> Vec<Check> {
+        self.checks.clone()
+    }
+
+    pub fn get_context(&self) -> Option<String> {
+        self.context.clone()
+    }
+
+    pub fn get_version(&self) -> u32 {
+        self.version
+    }
+
+    pub fn get_external_key(&self) -> Option<PublicKey> {
+        self.external_key
+    }
+
+    pub fn get_algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    pub fn get_revocation_id(&self) -> String {
+        self.revocation_id.clone()
+    }
 }
 
 #[php_class]
 #[php(name = "Biscuit\\Auth\\UnverifiedBiscuit")]
+#[php(modifier = unverified_biscuit_unserialize)]
 #[derive(Clone)]
 pub struct UnverifiedBiscuit(biscuit_auth::UnverifiedBiscuit);
 
 #[php_impl]
 impl UnverifiedBiscuit {
+    #[php(name = "fromBytes")]
+    pub fn from_bytes(data: BinarySlice<u8>) -> PhpResult<Self> {
+        Ok(Self(
+            biscuit_auth::UnverifiedBiscuit::from(data.as_ref()).format(FormatKind::Bytes)?,
+        ))
+    }
+
     #[php(name = "fromBase64")]
     pub fn from_base64(data: &str) -> PhpResult<Self> {
         Ok(Self(
@@ -112,6 +400,14 @@ impl UnverifiedBiscuit {
         ))
     }
 
+    pub fn to_bytes(&self) -> PhpResult<Vec<u8>> {
+        Ok(self.0.to_vec().format(FormatKind::Bytes)?)
+    }
+
+    pub fn to_base64(&self) -> PhpResult<String> {
+        Ok(self.0.to_base64().format(FormatKind::Base64)?)
+    }
+
     pub fn root_key_id(&self) -> Option<u32> {
         self.0.root_key_id()
     }
@@ -135,6 +431,25 @@ impl UnverifiedBiscuit {
         ))
     }
 
+    pub fn append_third_party(
+        &self,
+        external_key: &PublicKey,
+        block: &ThirdPartyBlock,
+    ) -> PhpResult<Self> {
+        let bytes = block.signed_by(external_key)?;
+        let result = self.0.append_third_party(&bytes);
+        let kind = match result {
+            Err(Token::AppendOnSealed) => BuildKind::Append,
+            _ => BuildKind::ThirdPartyAppend,
+        };
+        Ok(Self(result.build(kind)?))
+    }
+
+    pub fn third_party_request(&self) -> PhpResult<ThirdPartyRequest> {
+        let request = self.0.third_party_request().third_party()?;
+        Ok(ThirdPartyRequest(request))
+    }
+
     pub fn revocation_ids(&self) -> Vec<String> {
         self.0
             .revocation_identifiers()
@@ -143,12 +458,48 @@ impl UnverifiedBiscuit {
             .collect()
     }
 
-    pub fn verify(&self, root: &PublicKey) -> PhpResult<Biscuit> {
-        Ok(Biscuit(
-            self.0
-                .clone()
-                .verify(root.0)
-                .format(FormatKind::Signature)?,
-        ))
+    pub fn external_keys(&self) -> Vec<Option<PublicKey>> {
+        self.0
+            .external_public_keys()
+            .into_iter()
+            .map(|key| key.map(PublicKey))
+            .collect()
     }
+
+    pub fn block_external_key(&self, index: i64) -> PhpResult<Option<PublicKey>> {
+        let keys = self.0.external_public_keys();
+        let key = usize::try_from(index)
+            .ok()
+            .and_then(|i| keys.get(i).copied())
+            .ok_or(StaticError("block index out of range"))
+            .format(FormatKind::Snapshot)?;
+        Ok(key.map(PublicKey))
+    }
+
+    pub fn is_sealed(&self) -> PhpResult<bool> {
+        is_sealed(&self.0.to_vec().format(FormatKind::Bytes)?)
+    }
+
+    pub fn verify(&self, root: RootKey) -> PhpResult<Biscuit> {
+        Biscuit::verify_with(&root, FormatKind::Signature, |root| {
+            self.0.clone().verify(root).map_err(Token::Format)
+        })
+    }
+
+    pub fn __serialize(&self) -> PhpResult<ZBox<ZendHashTable>> {
+        let mut data = ZendHashTable::new();
+        data.insert("token", Binary::from(self.to_bytes()?))?;
+        Ok(data)
+    }
+}
+
+impl Unserialize for UnverifiedBiscuit {
+    fn unserialize(data: &ZendHashTable) -> PhpResult<Self> {
+        let token: BinarySlice<u8> = serialized_field(data, "token").format(FormatKind::Bytes)?;
+        Self::from_bytes(token)
+    }
+}
+
+fn unverified_biscuit_unserialize(builder: ClassBuilder) -> ClassBuilder {
+    with_unserialize::<UnverifiedBiscuit>(builder)
 }
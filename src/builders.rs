@@ -36,7 +36,7 @@ impl BiscuitBuilder {
             .clone()
             .build(&keypair)
             .build(BuildKind::Token)?;
-        Ok(Biscuit(token))
+        Ok(Biscuit(token, keypair.public()))
     }
 
     pub fn add_code(
@@ -89,6 +89,11 @@ impl BiscuitBuilder {
         Ok(())
     }
 
+    pub fn set_context(&mut self, context: String) -> PhpResult<()> {
+        self.0 = Some(take_builder(&mut self.0)?.context(context));
+        Ok(())
+    }
+
     pub fn __to_string(&self) -> PhpResult<String> {
         Ok(get_builder(&self.0)?.to_string())
     }
@@ -158,6 +163,11 @@ impl BlockBuilder {
         Ok(())
     }
 
+    pub fn set_context(&mut self, context: String) -> PhpResult<()> {
+        self.0 = Some(take_builder(&mut self.0)?.context(context));
+        Ok(())
+    }
+
     pub fn __to_string(&self) -> PhpResult<String> {
         Ok(format!("{}", get_builder(&self.0)?))
     }
@@ -1,10 +1,20 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
+use biscuit_auth::format::schema;
 use biscuit_parser::error::ParseError as UpstreamParseError;
+use ext_php_rs::binary_slice::BinarySlice;
+use ext_php_rs::boxed::ZBox;
+use ext_php_rs::builders::ClassBuilder;
+use ext_php_rs::convert::{FromZval, IntoZval};
+use ext_php_rs::flags::DataType;
 use ext_php_rs::prelude::*;
+use ext_php_rs::types::{ZendHashTable, Zval};
 
 use crate::errors::{DatalogKind, ResultExt};
-use crate::helpers::{MixedValue, mixed_value_to_term};
+use crate::helpers::{
+    MixedValue, Timestamp, Unserialize, map_to_term, mixed_value_to_term, serialized_field,
+    term_to_zval, with_unserialize,
+};
 use crate::keys::PublicKey;
 
 #[php_class]
@@ -35,10 +45,130 @@ impl ParseError {
     }
 }
 
+#[php_class]
+#[php(name = "Biscuit\\Auth\\Term")]
+#[derive(Debug, Clone)]
+pub struct Term(pub(crate) biscuit_auth::builder::Term);
+
+impl<'a> FromZval<'a> for Term {
+    const TYPE: DataType = <&'a Term as FromZval<'a>>::TYPE;
+
+    fn from_zval(zval: &'a Zval) -> Option<Self> {
+        <&'a Term as FromZval<'a>>::from_zval(zval).cloned()
+    }
+}
+
+#[php_impl]
+impl Term {
+    pub fn string(value: String) -> Self {
+        Self(biscuit_auth::builder::Term::Str(value))
+    }
+
+    pub fn bytes(value: BinarySlice<u8>) -> Self {
+        Self(biscuit_auth::builder::Term::Bytes(value.to_vec()))
+    }
+
+    pub fn integer(value: i64) -> Self {
+        Self(biscuit_auth::builder::Term::Integer(value))
+    }
+
+    #[php(name = "bool")]
+    pub fn boolean(value: bool) -> Self {
+        Self(biscuit_auth::builder::Term::Bool(value))
+    }
+
+    pub fn date(value: Timestamp) -> Self {
+        Self(biscuit_auth::builder::Term::Date(value.0))
+    }
+
+    pub fn set(values: Vec<MixedValue>) -> PhpResult<Self> {
+        let terms = values
+            .iter()
+            .map(mixed_value_to_term)
+            .collect::<PhpResult<BTreeSet<_>>>()?;
+        Ok(Self(biscuit_auth::builder::Term::Set(terms)))
+    }
+
+    pub fn null() -> Self {
+        Self(biscuit_auth::builder::Term::Null)
+    }
+
+    pub fn array(values: Vec<MixedValue>) -> PhpResult<Self> {
+        let terms = values
+            .iter()
+            .map(mixed_value_to_term)
+            .collect::<PhpResult<Vec<_>>>()?;
+        Ok(Self(biscuit_auth::builder::Term::Array(terms)))
+    }
+
+    pub fn map(values: HashMap<String, MixedValue>) -> PhpResult<Self> {
+        map_to_term(&values).map(Self)
+    }
+
+    pub fn get_type(&self) -> String {
+        use biscuit_auth::builder::Term as T;
+
+        match &self.0 {
+            T::Variable(_) => "variable",
+            T::Integer(_) => "integer",
+            T::Str(_) => "string",
+            T::Date(_) => "date",
+            T::Bytes(_) => "bytes",
+            T::Bool(_) => "bool",
+            T::Set(_) => "set",
+            T::Parameter(_) => "parameter",
+            T::Null => "null",
+            T::Array(_) => "array",
+            T::Map(_) => "map",
+        }
+        .to_string()
+    }
+
+    pub fn get_value(&self) -> PhpResult<Zval> {
+        term_to_zval(&self.0)
+    }
+
+    pub fn __to_string(&self) -> String {
+        format!("{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Origin {
+    Block(i64),
+    Authorizer,
+}
+
+impl From<&schema::Origin> for Origin {
+    fn from(origin: &schema::Origin) -> Self {
+        match origin.content {
+            Some(schema::origin::Content::Origin(id)) => Origin::Block(id.into()),
+            _ => Origin::Authorizer,
+        }
+    }
+}
+
+impl IntoZval for Origin {
+    const TYPE: DataType = DataType::Mixed;
+    const NULLABLE: bool = true;
+
+    fn set_zval(self, zv: &mut Zval, persistent: bool) -> ext_php_rs::error::Result<()> {
+        match self {
+            Origin::Block(id) => zv.set_long(id),
+            Origin::Authorizer => zv.set_string("authorizer", persistent)?,
+        }
+        Ok(())
+    }
+}
+
 #[php_class]
 #[php(name = "Biscuit\\Auth\\Rule")]
+#[php(modifier = rule_unserialize)]
 #[derive(Debug, Clone)]
-pub struct Rule(pub(crate) biscuit_auth::builder::Rule);
+pub struct Rule(
+    pub(crate) biscuit_auth::builder::Rule,
+    pub(crate) Option<Vec<Origin>>,
+);
 
 #[php_impl]
 impl Rule {
@@ -63,10 +193,10 @@ impl Rule {
             }
         }
 
-        Ok(Self(rule))
+        Ok(Self(rule, None))
     }
 
-    /// @param int|string|bool|null $value
+    /// @param int|string|bool|null|\DateTimeInterface|Term|list<mixed> $value
     pub fn set(&mut self, name: &str, value: MixedValue) -> PhpResult<()> {
         let term_value = mixed_value_to_term(&value)?;
         self.0.set(name, term_value).datalog(DatalogKind::Term)?;
@@ -78,15 +208,40 @@ impl Rule {
         Ok(())
     }
 
+    pub fn get_origins(&self) -> Option<Vec<Origin>> {
+        self.1.clone()
+    }
+
     pub fn __to_string(&self) -> String {
         format!("{}", self.0)
     }
+
+    pub fn __serialize(&self) -> PhpResult<ZBox<ZendHashTable>> {
+        let mut data = ZendHashTable::new();
+        data.insert("source", self.0.to_string())?;
+        Ok(data)
+    }
+}
+
+impl Unserialize for Rule {
+    fn unserialize(data: &ZendHashTable) -> PhpResult<Self> {
+        let source: &str = serialized_field(data, "source").datalog(DatalogKind::Rule)?;
+        Self::__construct(source, None, None)
+    }
+}
+
+fn rule_unserialize(builder: ClassBuilder) -> ClassBuilder {
+    with_unserialize::<Rule>(builder)
 }
 
 #[php_class]
 #[php(name = "Biscuit\\Auth\\Fact")]
+#[php(modifier = fact_unserialize)]
 #[derive(Debug, Clone)]
-pub struct Fact(pub(crate) biscuit_auth::builder::Fact);
+pub struct Fact(
+    pub(crate) biscuit_auth::builder::Fact,
+    pub(crate) Option<Vec<Origin>>,
+);
 
 #[php_impl]
 impl Fact {
@@ -104,10 +259,10 @@ impl Fact {
             }
         }
 
-        Ok(Self(fact))
+        Ok(Self(fact, None))
     }
 
-    /// @param int|string|bool|null $value
+    /// @param int|string|bool|null|\DateTimeInterface|Term|list<mixed> $value
     pub fn set(&mut self, name: &str, value: MixedValue) -> PhpResult<()> {
         let term_value = mixed_value_to_term(&value)?;
         self.0.set(name, term_value).datalog(DatalogKind::Term)?;
@@ -118,15 +273,48 @@ impl Fact {
         self.0.predicate.name.clone()
     }
 
+    pub fn get_terms(&self) -> PhpResult<Vec<Zval>> {
+        self.0.predicate.terms.iter().map(term_to_zval).collect()
+    }
+
+    pub fn get_typed_terms(&self) -> Vec<Term> {
+        self.0.predicate.terms.iter().cloned().map(Term).collect()
+    }
+
+    pub fn get_origins(&self) -> Option<Vec<Origin>> {
+        self.1.clone()
+    }
+
     pub fn __to_string(&self) -> String {
         format!("{}", self.0)
     }
+
+    pub fn __serialize(&self) -> PhpResult<ZBox<ZendHashTable>> {
+        let mut data = ZendHashTable::new();
+        data.insert("source", self.0.to_string())?;
+        Ok(data)
+    }
+}
+
+impl Unserialize for Fact {
+    fn unserialize(data: &ZendHashTable) -> PhpResult<Self> {
+        let source: &str = serialized_field(data, "source").datalog(DatalogKind::Fact)?;
+        Self::__construct(source, None)
+    }
+}
+
+fn fact_unserialize(builder: ClassBuilder) -> ClassBuilder {
+    with_unserialize::<Fact>(builder)
 }
 
 #[php_class]
 #[php(name = "Biscuit\\Auth\\Check")]
+#[php(modifier = check_unserialize)]
 #[derive(Debug, Clone)]
-pub struct Check(pub(crate) biscuit_auth::builder::Check);
+pub struct Check(
+    pub(crate) biscuit_auth::builder::Check,
+    pub(crate) Option<Vec<Origin>>,
+);
 
 #[php_impl]
 impl Check {
@@ -151,10 +339,10 @@ impl Check {
             }
         }
 
-        Ok(Self(check))
+        Ok(Self(check, None))
     }
 
-    /// @param int|string|bool|null $value
+    /// @param int|string|bool|null|\DateTimeInterface|Term|list<mixed> $value
     pub fn set(&mut self, name: &str, value: MixedValue) -> PhpResult<()> {
         let term_value = mixed_value_to_term(&value)?;
         self.0.set(name, term_value).datalog(DatalogKind::Term)?;
@@ -166,15 +354,40 @@ impl Check {
         Ok(())
     }
 
+    pub fn get_origins(&self) -> Option<Vec<Origin>> {
+        self.1.clone()
+    }
+
     pub fn __to_string(&self) -> String {
         format!("{}", self.0)
     }
+
+    pub fn __serialize(&self) -> PhpResult<ZBox<ZendHashTable>> {
+        let mut data = ZendHashTable::new();
+        data.insert("source", self.0.to_string())?;
+        Ok(data)
+    }
+}
+
+impl Unserialize for Check {
+    fn unserialize(data: &ZendHashTable) -> PhpResult<Self> {
+        let source: &str = serialized_field(data, "source").datalog(DatalogKind::Check)?;
+        Self::__construct(source, None, None)
+    }
+}
+
+fn check_unserialize(builder: ClassBuilder) -> ClassBuilder {
+    with_unserialize::<Check>(builder)
 }
 
 #[php_class]
 #[php(name = "Biscuit\\Auth\\Policy")]
+#[php(modifier = policy_unserialize)]
 #[derive(Debug, Clone)]
-pub struct Policy(pub(crate) biscuit_auth::builder::Policy);
+pub struct Policy(
+    pub(crate) biscuit_auth::builder::Policy,
+    pub(crate) Option<Vec<Origin>>,
+);
 
 #[php_impl]
 impl Policy {
@@ -199,10 +412,10 @@ impl Policy {
             }
         }
 
-        Ok(Self(policy))
+        Ok(Self(policy, None))
     }
 
-    /// @param int|string|bool|null $value
+    /// @param int|string|bool|null|\DateTimeInterface|Term|list<mixed> $value
     pub fn set(&mut self, name: &str, value: MixedValue) -> PhpResult<()> {
         let term_value = mixed_value_to_term(&value)?;
         self.0.set(name, term_value).datalog(DatalogKind::Term)?;
@@ -214,7 +427,28 @@ impl Policy {
         Ok(())
     }
 
+    pub fn get_origins(&self) -> Option<Vec<Origin>> {
+        self.1.clone()
+    }
+
     pub fn __to_string(&self) -> String {
         format!("{}", self.0)
     }
+
+    pub fn __serialize(&self) -> PhpResult<ZBox<ZendHashTable>> {
+        let mut data = ZendHashTable::new();
+        data.insert("source", self.0.to_string())?;
+        Ok(data)
+    }
+}
+
+impl Unserialize for Policy {
+    fn unserialize(data: &ZendHashTable) -> PhpResult<Self> {
+        let source: &str = serialized_field(data, "source").datalog(DatalogKind::Policy)?;
+        Self::__construct(source, None, None)
+    }
+}
+
+fn policy_unserialize(builder: ClassBuilder) -> ClassBuilder {
+    with_unserialize::<Policy>(builder)
 }
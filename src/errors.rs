@@ -1,4 +1,4 @@
-use biscuit_auth::error::{Logic, Token};
+use biscuit_auth::error::{Logic, RunLimit, Token};
 use biscuit_parser::error::LanguageError;
 use ext_php_rs::class::RegisteredClass;
 use ext_php_rs::convert::IntoZval;
@@ -47,6 +47,7 @@ type BoxedError = Box<dyn std::error::Error + Send + Sync + 'static>;
 pub(crate) enum KeyKind {
     PublicKey,
     PrivateKey,
+    KeyRing,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -75,6 +76,32 @@ pub(crate) enum BuildKind {
     ThirdPartyAppend,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LimitKind {
+    Facts,
+    Iterations,
+    Time,
+}
+
+impl LimitKind {
+    fn from_upstream(limit: &RunLimit) -> Option<Self> {
+        match limit {
+            RunLimit::TooManyFacts => Some(LimitKind::Facts),
+            RunLimit::TooManyIterations => Some(LimitKind::Iterations),
+            RunLimit::Timeout => Some(LimitKind::Time),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            LimitKind::Facts => "max_facts",
+            LimitKind::Iterations => "max_iterations",
+            LimitKind::Time => "max_time",
+        }
+    }
+}
+
 #[derive(Debug, Error)]
 pub(crate) enum BiscuitError {
     #[error("{source}")]
@@ -108,6 +135,12 @@ pub(crate) enum BiscuitError {
         policies: Vec<biscuit_auth::builder::Policy>,
     },
     #[error("{source}")]
+    Limit {
+        kind: LimitKind,
+        #[source]
+        source: Token,
+    },
+    #[error("{source}")]
     ThirdParty {
         #[source]
         source: BoxedError,
@@ -167,6 +200,51 @@ where
     }
 }
 
+pub(crate) trait RunResultExt<T> {
+    fn run(self, kind: BuildKind) -> Result<T, BiscuitError>;
+}
+
+impl<T> RunResultExt<T> for Result<T, Token> {
+    fn run(self, kind: BuildKind) -> Result<T, BiscuitError> {
+        self.map_err(|source| BiscuitError::from_run(source, kind))
+    }
+}
+
+impl BiscuitError {
+    fn from_run(source: Token, kind: BuildKind) -> Self {
+        match limit_kind(&source) {
+            Some(limit) => BiscuitError::Limit {
+                kind: limit,
+                source,
+            },
+            None => BiscuitError::Build {
+                kind,
+                source: Box::new(source),
+            },
+        }
+    }
+
+    pub(crate) fn from_authorization(
+        source: Token,
+        policies: Vec<biscuit_auth::builder::Policy>,
+    ) -> Self {
+        match limit_kind(&source) {
+            Some(limit) => BiscuitError::Limit {
+                kind: limit,
+                source,
+            },
+            None => BiscuitError::Authorization { source, policies },
+        }
+    }
+}
+
+fn limit_kind(error: &Token) -> Option<LimitKind> {
+    match error {
+        Token::RunLimit(limit) => LimitKind::from_upstream(limit),
+        _ => None,
+    }
+}
+
 pub(crate) fn collect_chain(err: &(dyn std::error::Error + 'static)) -> String {
     let mut parts: Vec<String> = vec![err.to_string()];
     let mut current = err.source();
@@ -212,6 +290,11 @@ marker_subclass!(
     "Biscuit\\Exception\\PrivateKeyException",
     KeyException
 );
+marker_subclass!(
+    KeyRingException,
+    "Biscuit\\Exception\\KeyRingException",
+    KeyException
+);
 
 #[php_class]
 #[php(name = "Biscuit\\Exception\\DatalogException")]
@@ -329,6 +412,21 @@ marker_subclass!(
 #[derive(Default, Clone)]
 pub struct BuilderStateException;
 
+#[php_class]
+#[php(name = "Biscuit\\Exception\\LimitException")]
+#[php(extends(BiscuitException))]
+#[derive(Debug, Default, Clone)]
+pub struct LimitException {
+    limit: String,
+}
+
+#[php_impl]
+impl LimitException {
+    pub fn get_limit(&self) -> String {
+        self.limit.clone()
+    }
+}
+
 #[php_class]
 #[php(name = "Biscuit\\Exception\\ThirdPartyException")]
 #[php(extends(BiscuitException))]
@@ -342,6 +440,7 @@ impl From<BiscuitError> for PhpException {
             BiscuitError::Key { kind, .. } => match kind {
                 KeyKind::PublicKey => PhpException::from_class::<PublicKeyException>(message),
                 KeyKind::PrivateKey => PhpException::from_class::<PrivateKeyException>(message),
+                KeyKind::KeyRing => PhpException::from_class::<KeyRingException>(message),
             },
             BiscuitError::Datalog { kind, source } => {
                 build_datalog_exception(kind, &*source, message)
@@ -362,6 +461,12 @@ impl From<BiscuitError> for PhpException {
                     PhpException::from_class::<ThirdPartyBlockAppendException>(message)
                 }
             },
+            BiscuitError::Limit { kind, .. } => into_php_exception(
+                LimitException {
+                    limit: kind.as_str().to_string(),
+                },
+                message,
+            ),
             BiscuitError::ThirdParty { .. } => {
                 PhpException::from_class::<ThirdPartyException>(message)
             }
@@ -1,25 +1,155 @@
 use std::collections::HashMap;
+use std::time::Duration;
+use std::{mem, ptr};
 
+use biscuit_auth::builder::Convert;
+use biscuit_auth::datalog::SymbolTable;
+use biscuit_auth::error::Format;
+use biscuit_auth::format::convert::{
+    proto_check_to_token_check, proto_fact_to_token_fact, proto_rule_to_token_rule,
+};
+use biscuit_auth::format::schema;
+use ext_php_rs::args::Arg;
+use ext_php_rs::boxed::ZBox;
+use ext_php_rs::builders::{ClassBuilder, FunctionBuilder};
+use ext_php_rs::class::RegisteredClass;
+use ext_php_rs::convert::{FromZval, IntoZval};
+use ext_php_rs::flags::{ClassFlags, DataType, MethodFlags};
 use ext_php_rs::prelude::*;
+use ext_php_rs::types::{ArrayKey, ZendCallable, ZendClassObject, ZendHashTable, ZendObject, Zval};
+use ext_php_rs::zend::{ClassEntry, ExecuteData, ce};
 
+use crate::datalog::Term;
 use crate::errors::{BiscuitError, DatalogKind, ResultExt, StaticError};
 use crate::keys::PublicKey;
 
 #[derive(Debug, ZvalConvert)]
 pub enum MixedValue {
+    Term(Term),
+    Date(Timestamp),
+    Null(Null),
     Long(i64),
     Bool(bool),
     ParsedStr(String),
+    Map(KeyedArray),
     Bytes(Vec<u8>),
     Array(Vec<MixedValue>),
     None,
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct Null;
+
+impl<'a> FromZval<'a> for Null {
+    const TYPE: DataType = DataType::Null;
+
+    fn from_zval(zval: &'a Zval) -> Option<Self> {
+        zval.is_null().then_some(Null)
+    }
+}
+
+impl IntoZval for Null {
+    const TYPE: DataType = DataType::Null;
+    const NULLABLE: bool = true;
+
+    fn set_zval(self, zv: &mut Zval, _persistent: bool) -> ext_php_rs::error::Result<()> {
+        zv.set_null();
+        Ok(())
+    }
+}
+
+/// A PHP array whose keys are not `0..n`, bound as a Datalog map.
+#[derive(Debug)]
+pub struct KeyedArray(pub(crate) HashMap<String, MixedValue>);
+
+impl<'a> FromZval<'a> for KeyedArray {
+    const TYPE: DataType = DataType::Array;
+
+    fn from_zval(zval: &'a Zval) -> Option<Self> {
+        let array = zval.array()?;
+        if array.has_sequential_keys() {
+            return None;
+        }
+        array.try_into().ok().map(Self)
+    }
+}
+
+impl IntoZval for KeyedArray {
+    const TYPE: DataType = DataType::Array;
+    const NULLABLE: bool = false;
+
+    fn set_zval(self, zv: &mut Zval, persistent: bool) -> ext_php_rs::error::Result<()> {
+        self.0.set_zval(zv, persistent)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Timestamp(pub(crate) u64);
+
+impl<'a> FromZval<'a> for Timestamp {
+    const TYPE: DataType = DataType::Object(Some("DateTimeInterface"));
+
+    fn from_zval(zval: &'a Zval) -> Option<Self> {
+        let interface = ClassEntry::try_find("DateTimeInterface")?;
+        if !zval.object()?.instance_of(interface) {
+            return None;
+        }
+        let timestamp = zval.try_call_method("getTimestamp", vec![]).ok()?.long()?;
+        u64::try_from(timestamp).ok().map(Self)
+    }
+}
+
+impl IntoZval for Timestamp {
+    const TYPE: DataType = DataType::Object(Some("DateTimeImmutable"));
+    const NULLABLE: bool = false;
+
+    fn set_zval(self, zv: &mut Zval, _persistent: bool) -> ext_php_rs::error::Result<()> {
+        *zv = date_to_zval(self.0)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, ZvalConvert)]
+pub enum DateValue {
+    DateTime(Timestamp),
+    Unix(u64),
+}
+
+impl DateValue {
+    pub(crate) fn timestamp(&self) -> u64 {
+        match self {
+            DateValue::DateTime(t) => t.0,
+            DateValue::Unix(t) => *t,
+        }
+    }
+}
+
+pub(crate) fn map_key(key: &str) -> biscuit_auth::builder::MapKey {
+    match key.parse::<i64>() {
+        Ok(i) if i.to_string() == key => biscuit_auth::builder::MapKey::Integer(i),
+        _ => biscuit_auth::builder::MapKey::Str(key.to_string()),
+    }
+}
+
+pub(crate) fn map_to_term(
+    values: &HashMap<String, MixedValue>,
+) -> PhpResult<biscuit_auth::builder::Term> {
+    let entries = values
+        .iter()
+        .map(|(k, v)| mixed_value_to_term(v).map(|term| (map_key(k), term)))
+        .collect::<PhpResult<std::collections::BTreeMap<_, _>>>()?;
+    Ok(biscuit_auth::builder::Term::Map(entries))
+}
+
 pub fn mixed_value_to_term(value: &MixedValue) -> PhpResult<biscuit_auth::builder::Term> {
     match value {
+        MixedValue::Term(t) => Ok(t.0.clone()),
+        MixedValue::Date(d) => Ok(biscuit_auth::builder::Term::Date(d.0)),
+        MixedValue::Null(_) => Ok(biscuit_auth::builder::Term::Null),
         MixedValue::Long(v) => Ok(biscuit_auth::builder::Term::Integer(*v)),
         MixedValue::Bool(b) => Ok(biscuit_auth::builder::Term::Bool(*b)),
         MixedValue::ParsedStr(s) => Ok(biscuit_auth::builder::Term::Str(s.clone())),
+        MixedValue::Map(map) => map_to_term(&map.0),
         MixedValue::Bytes(b) => Ok(biscuit_auth::builder::Term::Bytes(b.clone())),
         MixedValue::Array(arr) => {
             let terms: Result<Vec<_>, _> = arr.iter().map(mixed_value_to_term).collect();
@@ -32,6 +162,54 @@ pub fn mixed_value_to_term(value: &MixedValue) -> PhpResult<biscuit_auth::builde
     }
 }
 
+pub(crate) fn term_to_zval(term: &biscuit_auth::builder::Term) -> PhpResult<Zval> {
+    use biscuit_auth::builder::{MapKey, Term};
+
+    let mut zval = Zval::new();
+    match term {
+        Term::Integer(i) => zval.set_long(*i),
+        Term::Str(s) => zval.set_string(s, false)?,
+        Term::Bool(b) => zval.set_bool(*b),
+        Term::Bytes(b) => zval.set_binary(b.clone()),
+        Term::Date(d) => zval = date_to_zval(*d)?,
+        Term::Set(set) => zval.set_hashtable(terms_to_hashtable(set)?),
+        Term::Array(arr) => zval.set_hashtable(terms_to_hashtable(arr)?),
+        Term::Map(map) => {
+            let mut ht = ZendHashTable::new();
+            for (key, value) in map {
+                match key {
+                    MapKey::Integer(i) => ht.insert(ArrayKey::Long(*i), term_to_zval(value)?)?,
+                    MapKey::Str(s) => ht.insert(s.as_str(), term_to_zval(value)?)?,
+                    MapKey::Parameter(_) => {
+                        Err::<_, StaticError>(StaticError("map key is an unbound parameter"))
+                            .datalog(DatalogKind::Term)?
+                    }
+                }
+            }
+            zval.set_hashtable(ht);
+        }
+        Term::Null => zval.set_null(),
+        _ => Err::<_, StaticError>(StaticError("term cannot be converted to a PHP value"))
+            .datalog(DatalogKind::Term)?,
+    }
+    Ok(zval)
+}
+
+fn terms_to_hashtable<'a>(
+    terms: impl IntoIterator<Item = &'a biscuit_auth::builder::Term>,
+) -> PhpResult<ZBox<ZendHashTable>> {
+    let mut ht = ZendHashTable::new();
+    for term in terms {
+        ht.push(term_to_zval(term)?)?;
+    }
+    Ok(ht)
+}
+
+fn date_to_zval(timestamp: u64) -> ext_php_rs::error::Result<Zval> {
+    let create = ZendCallable::try_from_name("date_create_immutable")?;
+    create.try_call(vec![&format!("@{timestamp}")])
+}
+
 pub fn take_builder<T>(opt: &mut Option<T>) -> PhpResult<T> {
     opt.take()
         .ok_or_else(|| BiscuitError::BuilderConsumed("builder has already been consumed").into())
@@ -62,3 +240,146 @@ pub(crate) fn collect_scope_params(
         None => HashMap::new(),
     }
 }
+
+pub(crate) fn run_limits(
+    max_facts: u64,
+    max_iterations: u64,
+    max_time_micros: u64,
+) -> biscuit_auth::AuthorizerLimits {
+    biscuit_auth::AuthorizerLimits {
+        max_facts,
+        max_iterations,
+        max_time: Duration::from_micros(max_time_micros),
+    }
+}
+
+pub(crate) fn serialized_field<'a, T: FromZval<'a>>(
+    data: &'a ZendHashTable,
+    name: &str,
+) -> Result<T, StaticError> {
+    data.get(name)
+        .and_then(T::from_zval)
+        .ok_or(StaticError("malformed serialized data"))
+}
+
+/// Rebuilds an object from the array its `__serialize()` returned.
+pub(crate) trait Unserialize: RegisteredClass {
+    fn unserialize(data: &ZendHashTable) -> PhpResult<Self>;
+}
+
+/// Class modifier adding `__unserialize()`. `unserialize()` creates the object
+/// without calling its constructor, and `#[php_impl]` methods can't receive an
+/// object whose Rust value isn't initialized yet, so the method is registered
+/// by hand.
+pub(crate) fn with_unserialize<T: Unserialize>(builder: ClassBuilder) -> ClassBuilder {
+    builder.method(
+        FunctionBuilder::new("__unserialize", unserialize_handler::<T>)
+            .arg(Arg::new("data", DataType::Array))
+            .returns(DataType::Void, false, false),
+        MethodFlags::Public,
+    )
+}
+
+/// Lets `serialize()` and `unserialize()` through for a class that defines
+/// `__serialize()` and `__unserialize()`; ext-php-rs marks every class backed by
+/// a Rust object as not serializable when registering it.
+pub(crate) fn allow_serialization<T: RegisteredClass>() {
+    let ce = ptr::from_ref(T::get_metadata().ce()).cast_mut();
+    // SAFETY: the class entry lives as long as the module, and this only runs
+    // once, before any script can use the class.
+    unsafe { (*ce).ce_flags &= !ClassFlags::NotSerializable.bits() };
+}
+
+zend_fastcall! {
+    extern fn unserialize_handler<T: Unserialize>(ex: &mut ExecuteData, _: &mut Zval) {
+        let mut data = Arg::new("data", DataType::Array);
+        let (parser, this) = ex.parser_object();
+        if parser.arg(&mut data).parse().is_err() {
+            return;
+        }
+        let result = match (this.and_then(uninit_object::<T>), data.val::<&ZendHashTable>()) {
+            (Some(this), Some(data)) => T::unserialize(data).map(|value| {
+                this.initialize(value);
+            }),
+            (None, _) => Err("Failed to retrieve reference to `this` object.".into()),
+            (_, None) => Err(PhpException::new(
+                "__unserialize(): Argument #1 ($data) must be of type array".into(),
+                0,
+                ce::type_error(),
+            )),
+        };
+        if let Err(e) = result {
+            e.throw().expect("Failed to throw PHP exception.");
+        }
+    }
+}
+
+/// Finds the `ZendClassObject<T>` around an object, whether or not its Rust
+/// value has been initialized.
+fn uninit_object<T: RegisteredClass>(obj: &mut ZendObject) -> Option<&mut ZendClassObject<T>> {
+    let meta = T::get_metadata();
+    if !ptr::eq(obj.handlers, meta.handlers()) || !obj.instance_of(meta.ce()) {
+        return None;
+    }
+    // SAFETY: objects using `T`'s handlers are allocated by its `create_object`
+    // handler, which embeds them in a `ZendClassObject<T>`.
+    unsafe {
+        ptr::from_mut(obj)
+            .byte_sub(mem::offset_of!(ZendClassObject<T>, std))
+            .cast::<ZendClassObject<T>>()
+            .as_mut()
+    }
+}
+
+/// Builds the symbol table a serialized block or snapshot was written with.
+pub(crate) fn symbol_table(
+    symbols: Vec<String>,
+    public_keys: &[schema::PublicKey],
+) -> Result<SymbolTable, Format> {
+    let public_keys = public_keys
+        .iter()
+        .map(biscuit_auth::PublicKey::from_proto)
+        .collect::<Result<Vec<_>, _>>()?;
+    SymbolTable::from_symbols_and_public_keys(symbols, public_keys)
+}
+
+pub(crate) fn decode_facts(
+    facts: &[schema::Fact],
+    symbols: &SymbolTable,
+) -> Result<Vec<biscuit_auth::builder::Fact>, Format> {
+    facts
+        .iter()
+        .map(|fact| {
+            let fact = proto_fact_to_token_fact(fact)?;
+            biscuit_auth::builder::Fact::convert_from(&fact, symbols)
+        })
+        .collect()
+}
+
+pub(crate) fn decode_rules(
+    rules: &[schema::Rule],
+    version: u32,
+    symbols: &SymbolTable,
+) -> Result<Vec<biscuit_auth::builder::Rule>, Format> {
+    rules
+        .iter()
+        .map(|rule| {
+            let (rule, _) = proto_rule_to_token_rule(rule, version)?;
+            biscuit_auth::builder::Rule::convert_from(&rule, symbols)
+        })
+        .collect()
+}
+
+pub(crate) fn decode_checks(
+    checks: &[schema::Check],
+    version: u32,
+    symbols: &SymbolTable,
+) -> Result<Vec<biscuit_auth::builder::Check>, Format> {
+    checks
+        .iter()
+        .map(|check| {
+            let check = proto_check_to_token_check(check, version)?;
+            biscuit_auth::builder::Check::convert_from(&check, symbols)
+        })
+        .collect()
+}
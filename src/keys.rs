@@ -1,14 +1,35 @@
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
 use std::str::FromStr;
 
-use biscuit_auth::KeyPair as BiscuitKeyPair;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 use biscuit_auth::builder::Algorithm as BiscuitAlgorithm;
+use biscuit_auth::error::Format;
+use biscuit_auth::format::schema;
+use biscuit_auth::{KeyPair as BiscuitKeyPair, RootKeyProvider};
+use ext_php_rs::binary::Binary;
 use ext_php_rs::binary_slice::BinarySlice;
+use ext_php_rs::boxed::ZBox;
+use ext_php_rs::builders::ClassBuilder;
+use ext_php_rs::convert::{FromZval, IntoZval};
+use ext_php_rs::error::php_error;
+use ext_php_rs::flags::{DataType, ErrorType};
 use ext_php_rs::prelude::*;
+use ext_php_rs::types::{ZendCallable, ZendHashTable, Zval};
+use p256::ecdsa::signature::Verifier;
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
 
-use crate::errors::{KeyKind, ResultExt};
+use crate::errors::{BiscuitError, FormatKind, KeyKind, ResultExt, StaticError};
+use crate::helpers::{Unserialize, serialized_field, with_unserialize};
+
+const P256_SEED_DOMAIN: &[u8] = b"biscuit-php/p256-seed/v1";
 
 #[php_enum]
 #[php(name = "Biscuit\\Auth\\Algorithm")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Algorithm {
     #[php(value = 0)]
     Ed25519,
@@ -25,11 +46,146 @@ impl From<Algorithm> for BiscuitAlgorithm {
     }
 }
 
+impl From<schema::public_key::Algorithm> for Algorithm {
+    fn from(alg: schema::public_key::Algorithm) -> Self {
+        match alg {
+            schema::public_key::Algorithm::Ed25519 => Algorithm::Ed25519,
+            schema::public_key::Algorithm::Secp256r1 => Algorithm::Secp256r1,
+        }
+    }
+}
+
+struct Jwk {
+    algorithm: Algorithm,
+    x: Option<Vec<u8>>,
+    y: Option<Vec<u8>>,
+    d: Option<Zeroizing<Vec<u8>>>,
+}
+
+impl Jwk {
+    fn parse(input: &Zval, kind: KeyKind) -> PhpResult<Self> {
+        let decoded;
+        let table = match input.str() {
+            Some(json) => {
+                let decode = ZendCallable::try_from_name("json_decode")?;
+                decoded = decode.try_call(vec![&json, &true])?;
+                decoded.array()
+            }
+            None => input.array(),
+        }
+        .ok_or(StaticError("expected a JWK as an array or a JSON object"))
+        .key(kind)?;
+
+        let kty = table.get("kty").and_then(Zval::str);
+        let crv = table.get("crv").and_then(Zval::str);
+        let algorithm = match (kty, crv) {
+            (Some("OKP"), Some("Ed25519")) => Some(Algorithm::Ed25519),
+            (Some("EC"), Some("P-256")) => Some(Algorithm::Secp256r1),
+            _ => None,
+        }
+        .ok_or(StaticError(
+            "unsupported JWK: expected kty \"OKP\" with crv \"Ed25519\" or kty \"EC\" with crv \"P-256\"",
+        ))
+        .key(kind)?;
+
+        Ok(Self {
+            algorithm,
+            x: Self::member(table, "x", kind)?,
+            y: Self::member(table, "y", kind)?,
+            d: Self::member(table, "d", kind)?.map(Zeroizing::new),
+        })
+    }
+
+    fn member(table: &ZendHashTable, name: &str, kind: KeyKind) -> PhpResult<Option<Vec<u8>>> {
+        match table.get(name).and_then(Zval::str) {
+            Some(value) => Ok(Some(URL_SAFE_NO_PAD.decode(value).key(kind)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn public_key(&self, kind: KeyKind) -> PhpResult<biscuit_auth::PublicKey> {
+        let x = self
+            .x
+            .as_deref()
+            .ok_or(StaticError("JWK is missing the \"x\" member"))
+            .key(kind)?;
+        if x.len() != 32 {
+            Err::<(), _>(StaticError("JWK \"x\" member must be 32 bytes")).key(kind)?;
+        }
+        let bytes = match self.algorithm {
+            Algorithm::Ed25519 => x.to_vec(),
+            Algorithm::Secp256r1 => {
+                let y = self
+                    .y
+                    .as_deref()
+                    .ok_or(StaticError("JWK is missing the \"y\" member"))
+                    .key(kind)?;
+                if y.len() != 32 {
+                    Err::<(), _>(StaticError("JWK \"y\" member must be 32 bytes")).key(kind)?;
+                }
+                // Uncompressed SEC1 point, validated by PublicKey::from_bytes
+                [&[0x04], x, y].concat()
+            }
+        };
+        Ok(biscuit_auth::PublicKey::from_bytes(&bytes, self.algorithm.into()).key(kind)?)
+    }
+
+    fn encode_public(key: &biscuit_auth::PublicKey) -> PhpResult<ZBox<ZendHashTable>> {
+        let mut jwk = ZendHashTable::new();
+        match Algorithm::from(key.algorithm()) {
+            Algorithm::Ed25519 => {
+                jwk.insert("kty", "OKP")?;
+                jwk.insert("crv", "Ed25519")?;
+                jwk.insert("x", URL_SAFE_NO_PAD.encode(key.to_bytes()))?;
+            }
+            Algorithm::Secp256r1 => {
+                // The key's SubjectPublicKeyInfo ends with the uncompressed
+                // point: 0x04 || x || y.
+                let der = key.to_der().key(KeyKind::PublicKey)?;
+                let point = der
+                    .len()
+                    .checked_sub(65)
+                    .map(|start| &der[start..])
+                    .filter(|point| point[0] == 0x04)
+                    .ok_or(StaticError("invalid P-256 public key"))
+                    .key(KeyKind::PublicKey)?;
+                let (x, y) = point[1..].split_at(32);
+                jwk.insert("kty", "EC")?;
+                jwk.insert("crv", "P-256")?;
+                jwk.insert("x", URL_SAFE_NO_PAD.encode(x))?;
+                jwk.insert("y", URL_SAFE_NO_PAD.encode(y))?;
+            }
+        }
+        Ok(jwk)
+    }
+}
+
+// Secret scalars are held by the upstream ed25519-dalek and p256 signing keys, which zeroize
+// themselves on drop, so freeing the PHP object wipes them. Copies made here are wrapped in
+// `Zeroizing` and never reach `Debug`, `__toString` or `__debugInfo` output.
+const REDACTED: &str = "[redacted]";
+
+thread_local! {
+    static PRIVATE_KEY_SERIALIZATION: Cell<bool> = const { Cell::new(false) };
+}
+
+pub(crate) fn reset_private_key_serialization() {
+    PRIVATE_KEY_SERIALIZATION.set(false);
+}
+
 #[php_class]
 #[php(name = "Biscuit\\Auth\\KeyPair")]
-#[derive(Debug)]
 pub struct KeyPair(BiscuitKeyPair);
 
+impl fmt::Debug for KeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyPair")
+            .field("public", &self.0.public())
+            .field("private", &REDACTED)
+            .finish()
+    }
+}
+
 #[php_impl]
 impl KeyPair {
     pub fn __construct(alg: Option<Algorithm>) -> Self {
@@ -42,6 +198,36 @@ impl KeyPair {
         Self(BiscuitKeyPair::from(&private_key.0))
     }
 
+    #[php(name = "fromSeed")]
+    pub fn from_seed(seed: BinarySlice<u8>, alg: Option<Algorithm>) -> PhpResult<Self> {
+        let seed = <[u8; 32]>::try_from(seed.as_ref())
+            .map(Zeroizing::new)
+            .map_err(|_| StaticError("seed must be exactly 32 bytes"))
+            .key(KeyKind::PrivateKey)?;
+        let private = match alg.unwrap_or(Algorithm::Ed25519) {
+            Algorithm::Ed25519 => {
+                biscuit_auth::PrivateKey::from_bytes(&seed[..], BiscuitAlgorithm::Ed25519)
+                    .key(KeyKind::PrivateKey)?
+            }
+            Algorithm::Secp256r1 => (0u32..)
+                .find_map(|counter| {
+                    let scalar: Zeroizing<[u8; 32]> = Zeroizing::new(
+                        Sha256::new()
+                            .chain_update(P256_SEED_DOMAIN)
+                            .chain_update(&seed[..])
+                            .chain_update(counter.to_be_bytes())
+                            .finalize()
+                            .into(),
+                    );
+                    biscuit_auth::PrivateKey::from_bytes(&scalar[..], BiscuitAlgorithm::Secp256r1)
+                        .ok()
+                })
+                .ok_or(StaticError("cannot derive a P-256 key from this seed"))
+                .key(KeyKind::PrivateKey)?,
+        };
+        Ok(Self(BiscuitKeyPair::from(&private)))
+    }
+
     #[php(name = "getPublicKey")]
     pub fn get_public_key(&self) -> PublicKey {
         PublicKey(self.0.public())
@@ -51,10 +237,24 @@ impl KeyPair {
     pub fn get_private_key(&self) -> PrivateKey {
         PrivateKey(self.0.private())
     }
+
+    #[php(name = "getAlgorithm")]
+    pub fn get_algorithm(&self) -> Algorithm {
+        self.0.public().algorithm().into()
+    }
+
+    #[php(name = "__debugInfo")]
+    pub fn __debug_info(&self) -> PhpResult<ZBox<ZendHashTable>> {
+        let mut info = ZendHashTable::new();
+        info.insert("publicKey", self.0.public().to_string())?;
+        info.insert("privateKey", REDACTED)?;
+        Ok(info)
+    }
 }
 
 #[php_class]
 #[php(name = "Biscuit\\Auth\\PublicKey")]
+#[php(modifier = public_key_unserialize)]
 #[derive(Debug, Clone, Copy)]
 pub struct PublicKey(pub(crate) biscuit_auth::PublicKey);
 
@@ -93,6 +293,59 @@ impl PublicKey {
         self.0.to_bytes()
     }
 
+    #[php(name = "getAlgorithm")]
+    pub fn get_algorithm(&self) -> Algorithm {
+        self.0.algorithm().into()
+    }
+
+    pub fn equals(&self, other: &PublicKey) -> bool {
+        self.get_algorithm() == other.get_algorithm() && self.0.to_bytes() == other.0.to_bytes()
+    }
+
+    pub fn fingerprint(&self) -> PhpResult<String> {
+        let der = self.0.to_der().key(KeyKind::PublicKey)?;
+        Ok(hex::encode(Sha256::digest(&der)))
+    }
+
+    #[php(name = "fromJwk")]
+    pub fn from_jwk(jwk: &Zval) -> PhpResult<Self> {
+        Ok(Self(
+            Jwk::parse(jwk, KeyKind::PublicKey)?.public_key(KeyKind::PublicKey)?,
+        ))
+    }
+
+    pub fn to_jwk(&self) -> PhpResult<ZBox<ZendHashTable>> {
+        Jwk::encode_public(&self.0)
+    }
+
+    pub fn to_pem(&self) -> PhpResult<String> {
+        Ok(self.0.to_pem().key(KeyKind::PublicKey)?)
+    }
+
+    pub fn to_der(&self) -> PhpResult<Binary<u8>> {
+        Ok(Binary::from(self.0.to_der().key(KeyKind::PublicKey)?))
+    }
+
+    pub fn verify(&self, data: BinarySlice<u8>, signature: BinarySlice<u8>) -> bool {
+        // biscuit-auth does not export the Signature type taken by
+        // PublicKey::verify_signature(), so this checks signatures the same
+        // way: strict Ed25519, and DER-encoded ECDSA P-256 over SHA-256.
+        let key = self.0.to_bytes();
+        match Algorithm::from(self.0.algorithm()) {
+            Algorithm::Ed25519 => <[u8; 32]>::try_from(key.as_slice())
+                .ok()
+                .and_then(|key| ed25519_dalek::VerifyingKey::from_bytes(&key).ok())
+                .zip(ed25519_dalek::Signature::from_slice(signature.as_ref()).ok())
+                .is_some_and(|(key, signature)| {
+                    key.verify_strict(data.as_ref(), &signature).is_ok()
+                }),
+            Algorithm::Secp256r1 => p256::ecdsa::VerifyingKey::from_sec1_bytes(&key)
+                .ok()
+                .zip(p256::ecdsa::Signature::from_der(signature.as_ref()).ok())
+                .is_some_and(|(key, signature)| key.verify(data.as_ref(), &signature).is_ok()),
+        }
+    }
+
     pub fn to_hex(&self) -> String {
         self.0.to_string()
     }
@@ -100,13 +353,130 @@ impl PublicKey {
     pub fn __to_string(&self) -> String {
         self.0.to_string()
     }
+
+    pub fn __serialize(&self) -> PhpResult<ZBox<ZendHashTable>> {
+        let mut data = ZendHashTable::new();
+        data.insert("key", self.0.to_string())?;
+        Ok(data)
+    }
+}
+
+impl Unserialize for PublicKey {
+    fn unserialize(data: &ZendHashTable) -> PhpResult<Self> {
+        let key: &str = serialized_field(data, "key").key(KeyKind::PublicKey)?;
+        Self::__construct(key)
+    }
+}
+
+fn public_key_unserialize(builder: ClassBuilder) -> ClassBuilder {
+    with_unserialize::<PublicKey>(builder)
+}
+
+pub enum RootKey<'a> {
+    Key(biscuit_auth::PublicKey),
+    Ring(&'a KeyRing),
+    Provider(ZendCallable<'a>, RefCell<Option<PhpException>>),
+}
+
+impl<'a> FromZval<'a> for RootKey<'a> {
+    const TYPE: DataType = DataType::Mixed;
+
+    fn from_zval(zval: &'a Zval) -> Option<Self> {
+        if let Some(key) = <&PublicKey as FromZval>::from_zval(zval) {
+            return Some(RootKey::Key(key.0));
+        }
+        if let Some(ring) = <&KeyRing as FromZval>::from_zval(zval) {
+            return Some(RootKey::Ring(ring));
+        }
+        ZendCallable::from_zval(zval)
+            .map(|provider| RootKey::Provider(provider, RefCell::default()))
+    }
+}
+
+impl RootKey<'_> {
+    /// Surfaces the error raised by a root key provider callable instead of
+    /// the verification failure it caused.
+    pub(crate) fn verified<T, E>(&self, result: Result<T, E>, kind: FormatKind) -> PhpResult<T>
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        if let RootKey::Provider(_, failure) = self
+            && let Some(err) = failure.take()
+        {
+            return Err(err);
+        }
+        Ok(result.format(kind)?)
+    }
+}
+
+impl RootKeyProvider for RootKey<'_> {
+    fn choose(&self, key_id: Option<u32>) -> Result<biscuit_auth::PublicKey, Format> {
+        match self {
+            RootKey::Key(key) => Ok(*key),
+            RootKey::Ring(ring) => ring.choose(key_id),
+            RootKey::Provider(provider, failure) => {
+                let key = match provider.try_call(vec![&key_id]) {
+                    Ok(key) => key,
+                    Err(err) => {
+                        failure.replace(Some(rethrow(err)));
+                        return Err(Format::UnknownPublicKey);
+                    }
+                };
+                if key.is_null() {
+                    return Err(Format::UnknownPublicKey);
+                }
+                match <&PublicKey as FromZval>::from_zval(&key) {
+                    Some(key) => Ok(key.0),
+                    None => {
+                        let err = BiscuitError::Key {
+                            kind: KeyKind::KeyRing,
+                            source: Box::new(StaticError(
+                                "the root key provider must return a PublicKey or null",
+                            )),
+                        };
+                        failure.replace(Some(err.into()));
+                        Err(Format::UnknownPublicKey)
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Keeps the exception thrown by a PHP callable so it can be thrown again
+/// once control is back in the extension.
+fn rethrow(err: ext_php_rs::error::Error) -> PhpException {
+    match err {
+        ext_php_rs::error::Error::Exception(object) => match object.into_zval(false) {
+            Ok(object) => PhpException::default(String::new()).with_object(object),
+            Err(err) => err.into(),
+        },
+        err => err.into(),
+    }
 }
 
 #[php_class]
 #[php(name = "Biscuit\\Auth\\PrivateKey")]
-#[derive(Debug, Clone)]
+#[php(modifier = private_key_unserialize)]
+#[derive(Clone)]
 pub struct PrivateKey(pub(crate) biscuit_auth::PrivateKey);
 
+impl PrivateKey {
+    fn redacted(&self) -> String {
+        let prefix = match Algorithm::from(self.0.algorithm()) {
+            Algorithm::Ed25519 => "ed25519",
+            Algorithm::Secp256r1 => "secp256r1",
+        };
+        format!("{prefix}-private/{REDACTED}")
+    }
+}
+
+impl fmt::Debug for PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PrivateKey").field(&self.redacted()).finish()
+    }
+}
+
 #[php_impl]
 impl PrivateKey {
     pub fn __construct(data: &str) -> PhpResult<Self> {
@@ -150,15 +520,249 @@ impl PrivateKey {
         PublicKey(keypair.public())
     }
 
+    #[php(name = "getAlgorithm")]
+    pub fn get_algorithm(&self) -> Algorithm {
+        self.0.algorithm().into()
+    }
+
     pub fn to_bytes(&self) -> Vec<u8> {
         self.0.to_bytes().to_vec()
     }
 
+    #[php(name = "fromJwk")]
+    pub fn from_jwk(jwk: &Zval) -> PhpResult<Self> {
+        let jwk = Jwk::parse(jwk, KeyKind::PrivateKey)?;
+        let d = jwk
+            .d
+            .as_deref()
+            .ok_or(StaticError("JWK is missing the \"d\" member"))
+            .key(KeyKind::PrivateKey)?;
+        let key = biscuit_auth::PrivateKey::from_bytes(d, jwk.algorithm.into())
+            .key(KeyKind::PrivateKey)?;
+
+        if jwk.x.is_some() {
+            let expected = jwk.public_key(KeyKind::PrivateKey)?;
+            let derived = BiscuitKeyPair::from(&key).public();
+            (derived.to_bytes() == expected.to_bytes())
+                .then_some(())
+                .ok_or(StaticError(
+                    "JWK public members do not match the private key",
+                ))
+                .key(KeyKind::PrivateKey)?;
+        }
+        Ok(Self(key))
+    }
+
+    pub fn to_jwk(&self) -> PhpResult<ZBox<ZendHashTable>> {
+        let mut jwk = Jwk::encode_public(&BiscuitKeyPair::from(&self.0).public())?;
+        jwk.insert("d", URL_SAFE_NO_PAD.encode(&self.0.to_bytes()[..]))?;
+        Ok(jwk)
+    }
+
+    pub fn to_pem(&self) -> PhpResult<String> {
+        let pem = self.0.to_pem().key(KeyKind::PrivateKey)?;
+        Ok(pem.to_string())
+    }
+
+    pub fn to_der(&self) -> PhpResult<Binary<u8>> {
+        let der = self.0.to_der().key(KeyKind::PrivateKey)?;
+        Ok(Binary::from(der.to_vec()))
+    }
+
+    pub fn sign(&self, data: BinarySlice<u8>) -> PhpResult<Binary<u8>> {
+        let signature = BiscuitKeyPair::from(&self.0)
+            .sign(data.as_ref())
+            .key(KeyKind::PrivateKey)?;
+        Ok(Binary::from(signature.to_bytes().to_vec()))
+    }
+
     pub fn to_hex(&self) -> String {
+        php_error(
+            &ErrorType::Deprecated,
+            "Biscuit\\Auth\\PrivateKey::toHex() is deprecated, use exportSecret() instead",
+        );
+        self.export_secret()
+    }
+
+    pub fn export_secret(&self) -> String {
         self.0.to_prefixed_string()
     }
 
     pub fn __to_string(&self) -> String {
-        self.0.to_prefixed_string()
+        self.redacted()
+    }
+
+    #[php(name = "__debugInfo")]
+    pub fn __debug_info(&self) -> PhpResult<ZBox<ZendHashTable>> {
+        let mut info = ZendHashTable::new();
+        info.insert("key", self.redacted())?;
+        Ok(info)
+    }
+
+    #[php(name = "allowSerialization")]
+    pub fn allow_serialization(allow: bool) {
+        PRIVATE_KEY_SERIALIZATION.set(allow);
+    }
+
+    pub fn __serialize(&self) -> PhpResult<ZBox<ZendHashTable>> {
+        PRIVATE_KEY_SERIALIZATION
+            .get()
+            .then_some(())
+            .ok_or(StaticError(
+                "private key serialization is disabled, enable it with PrivateKey::allowSerialization(true)",
+            ))
+            .key(KeyKind::PrivateKey)?;
+        let mut data = ZendHashTable::new();
+        data.insert("key", self.0.to_prefixed_string())?;
+        Ok(data)
+    }
+}
+
+impl Unserialize for PrivateKey {
+    fn unserialize(data: &ZendHashTable) -> PhpResult<Self> {
+        let key: &str = serialized_field(data, "key").key(KeyKind::PrivateKey)?;
+        Self::__construct(key)
+    }
+}
+
+fn private_key_unserialize(builder: ClassBuilder) -> ClassBuilder {
+    with_unserialize::<PrivateKey>(builder)
+}
+
+#[php_class]
+#[php(name = "Biscuit\\Auth\\KeyRing")]
+#[derive(Debug, Clone, Default)]
+pub struct KeyRing {
+    keys: BTreeMap<u32, biscuit_auth::PublicKey>,
+    default: Option<biscuit_auth::PublicKey>,
+}
+
+impl KeyRing {
+    fn insert(&mut self, name: &str, key: biscuit_auth::PublicKey) -> PhpResult<()> {
+        if name == "default" {
+            self.default = Some(key);
+            return Ok(());
+        }
+        let id = name
+            .parse::<u32>()
+            .map_err(|_| StaticError("key ids must be unsigned 32-bit integers or \"default\""))
+            .key(KeyKind::KeyRing)?;
+        self.keys.insert(id, key);
+        Ok(())
+    }
+}
+
+impl RootKeyProvider for KeyRing {
+    fn choose(&self, key_id: Option<u32>) -> Result<biscuit_auth::PublicKey, Format> {
+        match key_id {
+            Some(id) => self.keys.get(&id).copied(),
+            None => self.default,
+        }
+        .ok_or(Format::UnknownPublicKey)
+    }
+}
+
+#[php_impl]
+impl KeyRing {
+    pub fn __construct(default: Option<&PublicKey>) -> Self {
+        Self {
+            keys: BTreeMap::new(),
+            default: default.map(|k| k.0),
+        }
+    }
+
+    #[php(name = "fromPemDirectory")]
+    pub fn from_pem_directory(path: &str) -> PhpResult<Self> {
+        // Goes through PHP's filesystem functions so open_basedir and stream
+        // wrappers apply as they would in userland.
+        let is_dir = ZendCallable::try_from_name("is_dir")?;
+        let scandir = ZendCallable::try_from_name("scandir")?;
+        let is_file = ZendCallable::try_from_name("is_file")?;
+        let is_readable = ZendCallable::try_from_name("is_readable")?;
+        let read = ZendCallable::try_from_name("file_get_contents")?;
+
+        if is_dir.try_call(vec![&path])?.bool() != Some(true) {
+            Err::<(), _>(StaticError("the key directory does not exist")).key(KeyKind::KeyRing)?;
+        }
+        let entries = scandir.try_call(vec![&path])?;
+        let entries = Vec::<String>::from_zval(&entries)
+            .ok_or(StaticError("cannot read the key directory"))
+            .key(KeyKind::KeyRing)?;
+
+        let mut ring = Self::default();
+        for entry in &entries {
+            let Some(name) = entry.strip_suffix(".pem") else {
+                continue;
+            };
+            let file = format!("{}/{entry}", path.trim_end_matches('/'));
+            if is_file.try_call(vec![&file])?.bool() != Some(true) {
+                continue;
+            }
+            let unreadable = || BiscuitError::Key {
+                kind: KeyKind::KeyRing,
+                source: format!("cannot read the key file {file}").into(),
+            };
+            if is_readable.try_call(vec![&file])?.bool() != Some(true) {
+                return Err(unreadable().into());
+            }
+            let pem = read
+                .try_call(vec![&file])?
+                .string()
+                .ok_or_else(unreadable)?;
+            let key = biscuit_auth::PublicKey::from_pem(&pem).key(KeyKind::KeyRing)?;
+            ring.insert(name, key)?;
+        }
+        Ok(ring)
+    }
+
+    #[php(name = "fromJson")]
+    pub fn from_json(json: &str) -> PhpResult<Self> {
+        let decode = ZendCallable::try_from_name("json_decode")?;
+        let decoded = decode.try_call(vec![&json, &true])?;
+        let entries = HashMap::<String, String>::from_zval(&decoded)
+            .ok_or(StaticError(
+                "expected a JSON object mapping key ids to public keys",
+            ))
+            .key(KeyKind::KeyRing)?;
+
+        let mut ring = Self::default();
+        for (name, data) in &entries {
+            let data = data.trim();
+            let key = if data.starts_with("-----BEGIN") {
+                biscuit_auth::PublicKey::from_pem(data).key(KeyKind::KeyRing)?
+            } else {
+                biscuit_auth::PublicKey::from_str(data).key(KeyKind::KeyRing)?
+            };
+            ring.insert(name, key)?;
+        }
+        Ok(ring)
+    }
+
+    pub fn add(&mut self, id: u32, key: &PublicKey) {
+        self.keys.insert(id, key.0);
+    }
+
+    pub fn remove(&mut self, id: u32) -> bool {
+        self.keys.remove(&id).is_some()
+    }
+
+    pub fn get(&self, id: u32) -> Option<PublicKey> {
+        self.keys.get(&id).copied().map(PublicKey)
+    }
+
+    pub fn has(&self, id: u32) -> bool {
+        self.keys.contains_key(&id)
+    }
+
+    pub fn ids(&self) -> Vec<u32> {
+        self.keys.keys().copied().collect()
+    }
+
+    pub fn set_default(&mut self, key: Option<&PublicKey>) {
+        self.default = key.map(|k| k.0);
+    }
+
+    pub fn get_default(&self) -> Option<PublicKey> {
+        self.default.map(PublicKey)
     }
 }
@@ -20,6 +20,8 @@ pub use helpers::*;
 pub use keys::*;
 pub use third_party::*;
 
+use std::sync::Once;
+
 use ext_php_rs::zend::ModuleEntry;
 use ext_php_rs::{info_table_end, info_table_row, info_table_start, prelude::*};
 
@@ -31,19 +33,49 @@ pub extern "C" fn php_module_info(_module: *mut ModuleEntry) {
     info_table_end!();
 }
 
+// Classes only exist once the module has started, after the startup hook
+// has run, so the first request lifts ext-php-rs' serialization ban.
+pub extern "C" fn php_request_startup(_type: i32, _module_number: i32) -> i32 {
+    static SERIALIZABLE: Once = Once::new();
+    SERIALIZABLE.call_once(|| {
+        helpers::allow_serialization::<Biscuit>();
+        helpers::allow_serialization::<UnverifiedBiscuit>();
+        helpers::allow_serialization::<AuthorizerBuilder>();
+        helpers::allow_serialization::<Rule>();
+        helpers::allow_serialization::<Fact>();
+        helpers::allow_serialization::<Check>();
+        helpers::allow_serialization::<Policy>();
+        helpers::allow_serialization::<PublicKey>();
+        helpers::allow_serialization::<PrivateKey>();
+    });
+    0
+}
+
+// Serialization settings are per request: a worker serving several requests
+// must not carry one request's root key or private key opt-in into the next.
+pub extern "C" fn php_request_shutdown(_type: i32, _module_number: i32) -> i32 {
+    biscuit::reset_unserialize_root_key();
+    keys::reset_private_key_serialization();
+    0
+}
+
 #[php_module]
 pub fn get_module(module: ModuleBuilder) -> ModuleBuilder {
     module
         .info_function(php_module_info)
+        .request_startup_function(php_request_startup)
+        .request_shutdown_function(php_request_shutdown)
         .enumeration::<Algorithm>()
         .class::<Biscuit>()
         .class::<UnverifiedBiscuit>()
+        .class::<Block>()
         .class::<Authorizer>()
         .class::<AuthorizerBuilder>()
         .class::<BiscuitBuilder>()
         .class::<BlockBuilder>()
         .class::<ThirdPartyRequest>()
         .class::<ThirdPartyBlock>()
+        .class::<Term>()
         .class::<Rule>()
         .class::<Fact>()
         .class::<Check>()
@@ -52,10 +84,12 @@ pub fn get_module(module: ModuleBuilder) -> ModuleBuilder {
         .class::<KeyPair>()
         .class::<PublicKey>()
         .class::<PrivateKey>()
+        .class::<KeyRing>()
         .class::<BiscuitException>()
         .class::<KeyException>()
         .class::<PublicKeyException>()
         .class::<PrivateKeyException>()
+        .class::<KeyRingException>()
         .class::<DatalogException>()
         .class::<FactException>()
         .class::<RuleException>()
@@ -74,8 +108,12 @@ pub fn get_module(module: ModuleBuilder) -> ModuleBuilder {
         .class::<AuthorizerBuildException>()
         .class::<ThirdPartyBlockAppendException>()
         .class::<BuilderStateException>()
+        .class::<LimitException>()
         .class::<ThirdPartyException>()
         .class::<MatchedPolicy>()
         .class::<FailedCheck>()
+        .class::<CheckResult>()
+        .class::<PolicyResult>()
+        .class::<AuthorizationResult>()
         .class::<AuthorizationException>()
 }
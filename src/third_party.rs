@@ -1,36 +1,178 @@
-use biscuit_auth::ThirdPartyBlock as BiscuitThirdPartyBlock;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE;
+use biscuit_auth::builder::{Convert, Scope};
+use biscuit_auth::error::Format;
+use biscuit_auth::format::convert::{proto_block_to_token_block, proto_scope_to_token_scope};
+use biscuit_auth::format::schema;
+use ext_php_rs::binary_slice::BinarySlice;
 use ext_php_rs::prelude::*;
+use prost::Message;
+use sha2::{Digest, Sha256};
 
 use crate::builders::BlockBuilder;
-use crate::errors::{BiscuitError, ResultExt};
-use crate::helpers::get_builder;
-use crate::keys::PrivateKey;
+use crate::errors::{BuildKind, FormatKind, ResultExt, StaticError};
+use crate::helpers::{decode_checks, decode_facts, decode_rules, get_builder, symbol_table};
+use crate::keys::{PrivateKey, PublicKey};
 
 #[php_class]
 #[php(name = "Biscuit\\Auth\\ThirdPartyRequest")]
-pub struct ThirdPartyRequest(pub(crate) Option<biscuit_auth::ThirdPartyRequest>);
+pub struct ThirdPartyRequest(pub(crate) biscuit_auth::ThirdPartyRequest);
+
+impl ThirdPartyRequest {
+    fn contents(&self) -> PhpResult<schema::ThirdPartyBlockRequest> {
+        let bytes = self.0.serialize().format(FormatKind::Bytes)?;
+        Ok(schema::ThirdPartyBlockRequest::decode(bytes.as_slice()).format(FormatKind::Bytes)?)
+    }
+
+    /// Upstream requests are consumed by `create_block` and cannot be
+    /// cloned, so each block is created from a serialized copy.
+    fn copy(&self) -> PhpResult<biscuit_auth::ThirdPartyRequest> {
+        let bytes = self.0.serialize().format(FormatKind::Bytes)?;
+        Ok(biscuit_auth::ThirdPartyRequest::deserialize(&bytes).format(FormatKind::Bytes)?)
+    }
+}
 
 #[php_impl]
 impl ThirdPartyRequest {
+    #[php(name = "fromBytes")]
+    pub fn from_bytes(data: BinarySlice<u8>) -> PhpResult<Self> {
+        let request = biscuit_auth::ThirdPartyRequest::deserialize(data.as_ref())
+            .format(FormatKind::Bytes)?;
+        Ok(Self(request))
+    }
+
+    #[php(name = "fromBase64")]
+    pub fn from_base64(data: &str) -> PhpResult<Self> {
+        let request =
+            biscuit_auth::ThirdPartyRequest::deserialize_base64(data).format(FormatKind::Base64)?;
+        Ok(Self(request))
+    }
+
+    pub fn to_bytes(&self) -> PhpResult<Vec<u8>> {
+        Ok(self.0.serialize().format(FormatKind::Bytes)?)
+    }
+
+    pub fn to_base64(&self) -> PhpResult<String> {
+        Ok(self.0.serialize_base64().format(FormatKind::Base64)?)
+    }
+
+    // Requests no longer carry the public keys of previous blocks: in v6
+    // they only exist in the legacy schema fields, which are never set.
+    pub fn get_previous_signature(&self) -> PhpResult<String> {
+        Ok(hex::encode(self.contents()?.previous_signature))
+    }
+
+    pub fn get_digest(&self) -> PhpResult<String> {
+        let bytes = self.0.serialize().format(FormatKind::Bytes)?;
+        Ok(hex::encode(Sha256::digest(&bytes)))
+    }
+
     pub fn create_block(
-        &mut self,
+        &self,
         private_key: &PrivateKey,
         block: &BlockBuilder,
     ) -> PhpResult<ThirdPartyBlock> {
-        let request = self.0.take().ok_or_else(|| {
-            PhpException::from(BiscuitError::BuilderConsumed(
-                "third-party request has already been consumed",
-            ))
-        })?;
-
-        let signed = request
-            .create_block(&private_key.0, get_builder(&block.0)?.clone())
+        let builder = get_builder(&block.0)?;
+        let signed = self
+            .copy()?
+            .create_block(&private_key.0, builder.clone())
             .third_party()?;
-        Ok(ThirdPartyBlock(signed))
+        ThirdPartyBlock::decode(&signed.serialize().format(FormatKind::Bytes)?)
+            .format(FormatKind::Bytes)
+            .map_err(Into::into)
     }
 }
 
 #[php_class]
 #[php(name = "Biscuit\\Auth\\ThirdPartyBlock")]
 #[derive(Clone)]
-pub struct ThirdPartyBlock(pub(crate) BiscuitThirdPartyBlock);
+pub struct ThirdPartyBlock(schema::ThirdPartyBlockContents);
+
+impl ThirdPartyBlock {
+    /// biscuit-auth has no way to deserialize a `ThirdPartyBlock`, so the
+    /// wrapper keeps the decoded contents and hands the bytes to
+    /// `UnverifiedBiscuit::append_third_party` instead.
+    fn decode(data: &[u8]) -> Result<Self, Format> {
+        let contents = schema::ThirdPartyBlockContents::decode(data)
+            .map_err(|e| Format::DeserializationError(e.to_string()))?;
+        let external_key =
+            biscuit_auth::PublicKey::from_proto(&contents.external_signature.public_key)?;
+        // Upstream unwraps the payload conversion when appending, so reject
+        // blocks that would not convert here.
+        let block = schema::Block::decode(contents.payload.as_slice())
+            .map_err(|e| Format::DeserializationError(e.to_string()))?;
+        proto_block_to_token_block(&block, Some(external_key))?;
+        Ok(Self(contents))
+    }
+
+    /// Returns the serialized block after checking it was signed by
+    /// `external_key`.
+    pub(crate) fn signed_by(&self, external_key: &PublicKey) -> PhpResult<Vec<u8>> {
+        if self.get_external_key()?.0 != external_key.0 {
+            Err::<(), _>(StaticError("the block was signed by an unexpected key"))
+                .build(BuildKind::ThirdPartyAppend)?;
+        }
+        Ok(self.0.encode_to_vec())
+    }
+
+    /// Prints the signed block back as Datalog. Third-party blocks carry
+    /// their own symbol table, so they can be decoded on their own.
+    fn source(payload: &[u8]) -> Result<String, Format> {
+        let block = schema::Block::decode(payload)
+            .map_err(|e| Format::DeserializationError(e.to_string()))?;
+        let symbols = symbol_table(block.symbols, &block.public_keys)?;
+        let version = block.version.unwrap_or_default();
+
+        let mut lines = Vec::new();
+        if !block.scope.is_empty() {
+            let scopes = block
+                .scope
+                .iter()
+                .map(|scope| {
+                    let scope = proto_scope_to_token_scope(scope)?;
+                    Ok(Scope::convert_from(&scope, &symbols)?.to_string())
+                })
+                .collect::<Result<Vec<_>, Format>>()?;
+            lines.push(format!("trusting {}", scopes.join(", ")));
+        }
+        let facts = decode_facts(&block.facts, &symbols)?;
+        let rules = decode_rules(&block.rules, version, &symbols)?;
+        let checks = decode_checks(&block.checks, version, &symbols)?;
+        lines.extend(facts.iter().map(|fact| format!("{fact};")));
+        lines.extend(rules.iter().map(|rule| format!("{rule};")));
+        lines.extend(checks.iter().map(|check| format!("{check};")));
+        Ok(lines.join("\n"))
+    }
+}
+
+#[php_impl]
+impl ThirdPartyBlock {
+    #[php(name = "fromBytes")]
+    pub fn from_bytes(data: BinarySlice<u8>) -> PhpResult<Self> {
+        Ok(Self::decode(data.as_ref()).format(FormatKind::Bytes)?)
+    }
+
+    #[php(name = "fromBase64")]
+    pub fn from_base64(data: &str) -> PhpResult<Self> {
+        let bytes = URL_SAFE.decode(data).format(FormatKind::Base64)?;
+        Ok(Self::decode(&bytes).format(FormatKind::Base64)?)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.encode_to_vec()
+    }
+
+    pub fn to_base64(&self) -> String {
+        URL_SAFE.encode(self.0.encode_to_vec())
+    }
+
+    pub fn get_external_key(&self) -> PhpResult<PublicKey> {
+        let key = biscuit_auth::PublicKey::from_proto(&self.0.external_signature.public_key)
+            .format(FormatKind::Bytes)?;
+        Ok(PublicKey(key))
+    }
+
+    pub fn get_source(&self) -> PhpResult<String> {
+        Ok(Self::source(&self.0.payload).format(FormatKind::Bytes)?)
+    }
+}
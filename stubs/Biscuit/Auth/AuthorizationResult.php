@@ -0,0 +1,103 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Biscuit\Auth;
+
+use Error;
+
+/**
+ * The full outcome of an authorization, returned by
+ * {@see Authorizer::evaluate()}.
+ *
+ * Lists every check with whether it passed, every policy with whether it
+ * matched, and the final decision.
+ *
+ * ```php
+ * $result = $authorizer->evaluate();
+ *
+ * if (!$result->isAllowed()) {
+ *     foreach ($result->getFailedChecks() as $check) {
+ *         echo $check->getRule(), "\n";
+ *     }
+ * }
+ * ```
+ */
+class AuthorizationResult
+{
+    /**
+     * Instances are created by the extension during authorization.
+     */
+    private function __construct() {}
+
+    /**
+     * Returns the final decision: `allow` when an `allow` policy matched
+     * and every check passed, `deny` otherwise.
+     *
+     * @return 'allow'|'deny'
+     */
+    public function getDecision(): string
+    {
+        throw new Error(
+            'Biscuit\Auth\AuthorizationResult::getDecision() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Returns whether the decision is `allow`.
+     */
+    public function isAllowed(): bool
+    {
+        throw new Error(
+            'Biscuit\Auth\AuthorizationResult::isAllowed() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Returns the first policy that matched, or null when none did.
+     */
+    public function getMatchedPolicy(): ?MatchedPolicy
+    {
+        throw new Error(
+            'Biscuit\Auth\AuthorizationResult::getMatchedPolicy() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Returns every check from the authorizer and the token, in
+     * evaluation order.
+     *
+     * @return list<CheckResult>
+     */
+    public function getChecks(): array
+    {
+        throw new Error(
+            'Biscuit\Auth\AuthorizationResult::getChecks() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Returns the checks that failed, as reported by
+     * {@see \Biscuit\Exception\AuthorizationException::getFailedChecks()}.
+     *
+     * @return list<FailedCheck>
+     */
+    public function getFailedChecks(): array
+    {
+        throw new Error(
+            'Biscuit\Auth\AuthorizationResult::getFailedChecks() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Returns every policy, in declaration order.
+     *
+     * @return list<PolicyResult>
+     */
+    public function getPolicies(): array
+    {
+        throw new Error(
+            'Biscuit\Auth\AuthorizationResult::getPolicies() should be implemented by the biscuit_php extension.',
+        );
+    }
+}
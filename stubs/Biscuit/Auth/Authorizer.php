@@ -6,6 +6,7 @@ namespace Biscuit\Auth;
 
 use Biscuit\Exception\AuthorizationException;
 use Biscuit\Exception\AuthorizerBuildException;
+use Biscuit\Exception\LimitException;
 use Biscuit\Exception\SnapshotException;
 use Error;
 
@@ -41,27 +42,170 @@ class Authorizer
      * throws an {@see AuthorizationException} carrying the matched `deny`
      * policy (when one matched) and the list of failed checks.
      *
+     * Runs with the limits configured through
+     * {@see AuthorizerBuilder::setLimits()}, or biscuit's defaults.
+     *
      * @throws AuthorizationException If a check fails or no `allow` policy matches.
+     * @throws LimitException If the Datalog evaluation exceeds a run limit.
      */
     public function authorize(): MatchedPolicy
     {
         throw new Error('Biscuit\Auth\Authorizer::authorize() should be implemented by the biscuit_php extension.');
     }
 
+    /**
+     * Same as {@see Authorizer::authorize()}, with explicit run limits
+     * for this evaluation only.
+     *
+     * @param non-negative-int $max_facts Maximum number of facts in the world.
+     * @param non-negative-int $max_iterations Maximum number of rule evaluation rounds.
+     * @param non-negative-int $max_time_micros Maximum evaluation time, in microseconds.
+     *
+     * @throws AuthorizationException If a check fails or no `allow` policy matches.
+     * @throws LimitException If the Datalog evaluation exceeds one of the limits.
+     */
+    public function authorizeWithLimits(int $max_facts, int $max_iterations, int $max_time_micros): MatchedPolicy
+    {
+        throw new Error(
+            'Biscuit\Auth\Authorizer::authorizeWithLimits() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Runs the checks and policies like {@see Authorizer::authorize()},
+     * but reports the outcome instead of throwing when authorization is
+     * denied: every check with whether it passed, every policy with
+     * whether it matched, and the final decision.
+     *
+     * @throws LimitException If the Datalog evaluation exceeds a run limit.
+     * @throws AuthorizationException If the evaluation fails for another reason than denied access.
+     */
+    public function evaluate(): AuthorizationResult
+    {
+        throw new Error('Biscuit\Auth\Authorizer::evaluate() should be implemented by the biscuit_php extension.');
+    }
+
     /**
      * Runs a rule against the authorizer's Datalog engine and returns the
      * facts it produces. The query only sees facts from the authorizer and
-     * the token's authority block.
+     * the token's authority block; use {@see Authorizer::queryAll()} to
+     * include facts from every block.
      *
      * @return list<Fact>
      *
      * @throws AuthorizerBuildException If the query cannot be executed.
+     * @throws LimitException If the Datalog evaluation exceeds a run limit.
      */
     public function query(Rule $rule): array
     {
         throw new Error('Biscuit\Auth\Authorizer::query() should be implemented by the biscuit_php extension.');
     }
 
+    /**
+     * Same as {@see Authorizer::query()}, with explicit run limits for
+     * this query only.
+     *
+     * @param non-negative-int $max_facts Maximum number of facts in the world.
+     * @param non-negative-int $max_iterations Maximum number of rule evaluation rounds.
+     * @param non-negative-int $max_time_micros Maximum evaluation time, in microseconds.
+     *
+     * @return list<Fact>
+     *
+     * @throws AuthorizerBuildException If the query cannot be executed.
+     * @throws LimitException If the Datalog evaluation exceeds one of the limits.
+     */
+    public function queryWithLimits(Rule $rule, int $max_facts, int $max_iterations, int $max_time_micros): array
+    {
+        throw new Error(
+            'Biscuit\Auth\Authorizer::queryWithLimits() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Runs a rule against the authorizer's Datalog engine and returns the
+     * facts it produces, regardless of trust scope: facts from every block,
+     * including third-party blocks, are visible to the query.
+     *
+     * @return list<Fact>
+     *
+     * @throws AuthorizerBuildException If the query cannot be executed.
+     * @throws LimitException If the Datalog evaluation exceeds a run limit.
+     */
+    public function queryAll(Rule $rule): array
+    {
+        throw new Error('Biscuit\Auth\Authorizer::queryAll() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Same as {@see Authorizer::queryAll()}, with explicit run limits for
+     * this query only.
+     *
+     * @param non-negative-int $max_facts Maximum number of facts in the world.
+     * @param non-negative-int $max_iterations Maximum number of rule evaluation rounds.
+     * @param non-negative-int $max_time_micros Maximum evaluation time, in microseconds.
+     *
+     * @return list<Fact>
+     *
+     * @throws AuthorizerBuildException If the query cannot be executed.
+     * @throws LimitException If the Datalog evaluation exceeds one of the limits.
+     */
+    public function queryAllWithLimits(Rule $rule, int $max_facts, int $max_iterations, int $max_time_micros): array
+    {
+        throw new Error(
+            'Biscuit\Auth\Authorizer::queryAllWithLimits() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Returns the facts in the authorizer's world, each annotated with the
+     * blocks it came from (see {@see Fact::getOrigins()}). Facts generated
+     * by rules appear once the authorizer has been evaluated.
+     *
+     * @return list<Fact>
+     *
+     * @throws SnapshotException If the authorizer's contents cannot be decoded.
+     */
+    public function getFacts(): array
+    {
+        throw new Error('Biscuit\Auth\Authorizer::getFacts() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the rules in the authorizer's world, each annotated with the
+     * blocks it came from (see {@see Rule::getOrigins()}).
+     *
+     * @return list<Rule>
+     *
+     * @throws SnapshotException If the authorizer's contents cannot be decoded.
+     */
+    public function getRules(): array
+    {
+        throw new Error('Biscuit\Auth\Authorizer::getRules() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the authorizer's checks followed by the token's checks, each
+     * annotated with the block it came from (see {@see Check::getOrigins()}).
+     *
+     * @return list<Check>
+     *
+     * @throws SnapshotException If the authorizer's contents cannot be decoded.
+     */
+    public function getChecks(): array
+    {
+        throw new Error('Biscuit\Auth\Authorizer::getChecks() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the authorizer's policies, in evaluation order.
+     *
+     * @return list<Policy>
+     */
+    public function getPolicies(): array
+    {
+        throw new Error('Biscuit\Auth\Authorizer::getPolicies() should be implemented by the biscuit_php extension.');
+    }
+
     /**
      * Serializes the authorizer state to a base64 snapshot.
      *
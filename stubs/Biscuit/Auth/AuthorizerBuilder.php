@@ -8,10 +8,12 @@ use Biscuit\Exception\AuthorizerBuildException;
 use Biscuit\Exception\BuilderStateException;
 use Biscuit\Exception\CheckException;
 use Biscuit\Exception\FactException;
+use Biscuit\Exception\LimitException;
 use Biscuit\Exception\PolicyException;
 use Biscuit\Exception\RuleException;
 use Biscuit\Exception\SnapshotException;
 use Biscuit\Exception\TermException;
+use DateTimeInterface;
 use Error;
 
 /**
@@ -37,7 +39,7 @@ class AuthorizerBuilder
      * Creates a builder, optionally seeded with Datalog source code
      * (equivalent to calling {@see AuthorizerBuilder::addCode()}).
      *
-     * @param array<string, int|bool|string|list<int|bool|string>>|null $params Values for `{name}` parameters.
+     * @param array<string, int|bool|string|null|DateTimeInterface|Term|list<int|bool|string|null|DateTimeInterface|Term>>|null $params Values for `{name}` parameters.
      * @param array<string, PublicKey>|null $scope_params Public keys for trust scope parameters.
      *
      * @throws TermException If the source cannot be parsed or a parameter cannot be applied.
@@ -53,7 +55,7 @@ class AuthorizerBuilder
      * Parses Datalog source code (facts, rules, checks, and policies) into
      * the builder.
      *
-     * @param array<string, int|bool|string|list<int|bool|string>>|null $params Values for `{name}` parameters.
+     * @param array<string, int|bool|string|null|DateTimeInterface|Term|list<int|bool|string|null|DateTimeInterface|Term>>|null $params Values for `{name}` parameters.
      * @param array<string, PublicKey>|null $scope_params Public keys for trust scope parameters.
      *
      * @throws TermException If the source cannot be parsed or a parameter cannot be applied.
@@ -114,17 +116,45 @@ class AuthorizerBuilder
     }
 
     /**
-     * Adds a fact with the current time (`time(<current timestamp>)`).
+     * Adds a `time(...)` fact: the current time by default, or the given
+     * instant (a date or a Unix timestamp) to authorize "as of" that moment.
+     *
+     * The fact is part of the builder state, so it is kept by snapshots.
+     *
+     * ```php
+     * $authBuilder->setTime(new DateTimeImmutable('2024-05-01T12:00:00Z'));
+     * ```
+     *
+     * @param DateTimeInterface|non-negative-int|null $at
      *
      * @throws BuilderStateException If the builder has already been consumed.
      */
-    public function setTime(): void
+    public function setTime(DateTimeInterface|int|null $at = null): void
     {
         throw new Error(
             'Biscuit\Auth\AuthorizerBuilder::setTime() should be implemented by the biscuit_php extension.',
         );
     }
 
+    /**
+     * Sets the Datalog run limits used by the built authorizer's
+     * {@see Authorizer::authorize()} and {@see Authorizer::query()}.
+     *
+     * Exceeding a limit throws a {@see LimitException}.
+     *
+     * @param non-negative-int $max_facts Maximum number of facts in the world.
+     * @param non-negative-int $max_iterations Maximum number of rule evaluation rounds.
+     * @param non-negative-int $max_time_micros Maximum evaluation time, in microseconds.
+     *
+     * @throws BuilderStateException If the builder has already been consumed.
+     */
+    public function setLimits(int $max_facts, int $max_iterations, int $max_time_micros): void
+    {
+        throw new Error(
+            'Biscuit\Auth\AuthorizerBuilder::setLimits() should be implemented by the biscuit_php extension.',
+        );
+    }
+
     /**
      * Merges the content of another authorizer builder into this builder.
      *
@@ -205,10 +235,37 @@ class AuthorizerBuilder
         );
     }
 
+    /**
+     * Serializes the builder as a raw snapshot, see {@see AuthorizerBuilder::rawSnapshot()}.
+     *
+     * @return array{snapshot: string}
+     */
+    public function __serialize(): array
+    {
+        throw new Error(
+            'Biscuit\Auth\AuthorizerBuilder::__serialize() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Restores the builder from its raw snapshot.
+     *
+     * @param array{snapshot: string} $data
+     *
+     * @throws SnapshotException If the payload is malformed or the snapshot cannot be deserialized.
+     */
+    public function __unserialize(array $data): void
+    {
+        throw new Error(
+            'Biscuit\Auth\AuthorizerBuilder::__unserialize() should be implemented by the biscuit_php extension.',
+        );
+    }
+
     /**
      * Builds the authorizer from a verified token.
      *
      * @throws AuthorizerBuildException If the authorizer cannot be built.
+     * @throws LimitException If loading the token exceeds a run limit.
      * @throws BuilderStateException If the builder has already been consumed.
      */
     public function build(#[\SensitiveParameter] Biscuit $token): Authorizer
@@ -220,6 +277,7 @@ class AuthorizerBuilder
      * Builds the authorizer without a token.
      *
      * @throws AuthorizerBuildException If the authorizer cannot be built.
+     * @throws LimitException If loading the authorizer exceeds a run limit.
      * @throws BuilderStateException If the builder has already been consumed.
      */
     public function buildUnauthenticated(): Authorizer
@@ -5,9 +5,11 @@ declare(strict_types=1);
 namespace Biscuit\Auth;
 
 use Biscuit\Exception\Base64Exception;
+use Biscuit\Exception\BiscuitBuildException;
 use Biscuit\Exception\BlockAppendException;
 use Biscuit\Exception\BuilderStateException;
 use Biscuit\Exception\BytesException;
+use Biscuit\Exception\KeyRingException;
 use Biscuit\Exception\SnapshotException;
 use Biscuit\Exception\ThirdPartyBlockAppendException;
 use Biscuit\Exception\ThirdPartyException;
@@ -55,9 +57,20 @@ class Biscuit
      * Deserializes a token from raw bytes (binary string) and verifies its
      * signatures against the root public key.
      *
-     * @throws BytesException If the input is not a valid token or signature verification fails.
+     * `$root` is either the root public key, a {@see KeyRing}, or a
+     * callable receiving the token's root key id (see
+     * {@see BiscuitBuilder::setRootKeyId()}) and returning the matching
+     * public key.
+     *
+     * A callable returns null when it does not know the key id; exceptions
+     * it throws are propagated unchanged.
+     *
+     * @param PublicKey|KeyRing|callable(?int): ?PublicKey $root
+     *
+     * @throws BytesException If the input is not a valid token, no root key is found, or signature verification fails.
+     * @throws KeyRingException If the callable returns something other than a PublicKey or null.
      */
-    public static function fromBytes(string $data, PublicKey $root): Biscuit
+    public static function fromBytes(string $data, PublicKey|KeyRing|callable $root): Biscuit
     {
         throw new Error('Biscuit\Auth\Biscuit::fromBytes() should be implemented by the biscuit_php extension.');
     }
@@ -66,9 +79,23 @@ class Biscuit
      * Deserializes a token from a URL-safe base64 string and verifies its
      * signatures against the root public key.
      *
-     * @throws Base64Exception If the input is not a valid token or signature verification fails.
+     * `$root` is either the root public key, a {@see KeyRing}, or a
+     * callable receiving the token's root key id and returning the
+     * matching public key:
+     *
+     * ```php
+     * $token = Biscuit::fromBase64($data, fn (?int $rootKeyId): ?PublicKey => $keys[$rootKeyId ?? 0] ?? null);
+     * ```
+     *
+     * A callable returns null when it does not know the key id; exceptions
+     * it throws are propagated unchanged.
+     *
+     * @param PublicKey|KeyRing|callable(?int): ?PublicKey $root
+     *
+     * @throws Base64Exception If the input is not a valid token, no root key is found, or signature verification fails.
+     * @throws KeyRingException If the callable returns something other than a PublicKey or null.
      */
-    public static function fromBase64(string $data, PublicKey $root): Biscuit
+    public static function fromBase64(string $data, PublicKey|KeyRing|callable $root): Biscuit
     {
         throw new Error('Biscuit\Auth\Biscuit::fromBase64() should be implemented by the biscuit_php extension.');
     }
@@ -117,7 +144,7 @@ class Biscuit
      * Adds an attenuation block and returns the new token; the original
      * token is left unchanged.
      *
-     * @throws BlockAppendException If the block cannot be appended.
+     * @throws BlockAppendException If the block cannot be appended, e.g. because the token is sealed.
      * @throws BuilderStateException If $block has already been consumed.
      */
     public function append(BlockBuilder $block): Biscuit
@@ -131,6 +158,7 @@ class Biscuit
      *
      * @param PublicKey $external_key The third party's public key.
      *
+     * @throws BlockAppendException If the token is sealed.
      * @throws ThirdPartyBlockAppendException If the block cannot be appended.
      */
     public function appendThirdParty(PublicKey $external_key, ThirdPartyBlock $block): Biscuit
@@ -138,6 +166,33 @@ class Biscuit
         throw new Error('Biscuit\Auth\Biscuit::appendThirdParty() should be implemented by the biscuit_php extension.');
     }
 
+    /**
+     * Returns a sealed copy of the token: its last signature is finalized
+     * so no block can be appended anymore. The original token is left
+     * unchanged.
+     *
+     * ```php
+     * $final = $token->seal();
+     * $final->isSealed(); // true
+     * ```
+     *
+     * @throws BiscuitBuildException If the token cannot be sealed (e.g. it is already sealed).
+     */
+    public function seal(): Biscuit
+    {
+        throw new Error('Biscuit\Auth\Biscuit::seal() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns whether the token is sealed, i.e. no block can be appended.
+     *
+     * @throws BytesException If the token cannot be serialized.
+     */
+    public function isSealed(): bool
+    {
+        throw new Error('Biscuit\Auth\Biscuit::isSealed() should be implemented by the biscuit_php extension.');
+    }
+
     /**
      * Creates the request to send to a third party so it can produce a
      * signed block for this token.
@@ -179,6 +234,38 @@ class Biscuit
         throw new Error('Biscuit\Auth\Biscuit::blockExternalKey() should be implemented by the biscuit_php extension.');
     }
 
+    /**
+     * Returns the context string attached to the given block, or null when
+     * the block has none.
+     *
+     * @throws SnapshotException If the block index is out of range.
+     */
+    public function blockContext(int $index): ?string
+    {
+        throw new Error('Biscuit\Auth\Biscuit::blockContext() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns a structured view of the given block: its facts, rules and
+     * checks, context, Datalog version, external key, signature algorithm
+     * and revocation id.
+     *
+     * ```php
+     * $block = $token->getBlock(0);
+     * foreach ($block->getFacts() as $fact) {
+     *     echo "{$fact}\n";
+     * }
+     * $revocationId = $block->getRevocationId();
+     * ```
+     *
+     * @throws SnapshotException If the block index is out of range.
+     * @throws BytesException If the block cannot be decoded.
+     */
+    public function getBlock(int $index): Block
+    {
+        throw new Error('Biscuit\Auth\Biscuit::getBlock() should be implemented by the biscuit_php extension.');
+    }
+
     /**
      * Pretty-prints the token content.
      */
@@ -186,4 +273,40 @@ class Biscuit
     {
         throw new Error('Biscuit\Auth\Biscuit::__toString() should be implemented by the biscuit_php extension.');
     }
+
+    /**
+     * Sets the root key that {@see Biscuit::__unserialize()} verifies tokens against, for the
+     * current request. Unserialized tokens are verified again, so a tampered cache or session
+     * entry is rejected. Pass null to clear it; it is also cleared when the request ends.
+     *
+     * @throws KeyRingException If the root key cannot be kept.
+     */
+    public static function setUnserializeRootKey(PublicKey|KeyRing|null $root): void
+    {
+        throw new Error(
+            'Biscuit\Auth\Biscuit::setUnserializeRootKey() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * @return array{token: string}
+     */
+    public function __serialize(): array
+    {
+        throw new Error('Biscuit\Auth\Biscuit::__serialize() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Restores a token from {@see Biscuit::__serialize()} output, verifying its signatures against
+     * the key set with {@see Biscuit::setUnserializeRootKey()}.
+     *
+     * @param array{token: string} $data
+     *
+     * @throws BytesException If the payload is malformed, the token is invalid, or signature verification fails.
+     * @throws KeyRingException If no root key was set.
+     */
+    public function __unserialize(array $data): void
+    {
+        throw new Error('Biscuit\Auth\Biscuit::__unserialize() should be implemented by the biscuit_php extension.');
+    }
 }
@@ -10,6 +10,7 @@ use Biscuit\Exception\CheckException;
 use Biscuit\Exception\FactException;
 use Biscuit\Exception\RuleException;
 use Biscuit\Exception\TermException;
+use DateTimeInterface;
 use Error;
 
 /**
@@ -32,7 +33,7 @@ class BiscuitBuilder
      * Creates a builder, optionally seeded with Datalog source code
      * (equivalent to calling {@see BiscuitBuilder::addCode()}).
      *
-     * @param array<string, int|bool|string|list<int|bool|string>>|null $params Values for `{name}` parameters.
+     * @param array<string, int|bool|string|null|DateTimeInterface|Term|list<int|bool|string|null|DateTimeInterface|Term>>|null $params Values for `{name}` parameters.
      * @param array<string, PublicKey>|null $scope_params Public keys for trust scope parameters.
      *
      * @throws TermException If the source cannot be parsed or a parameter cannot be applied.
@@ -59,7 +60,7 @@ class BiscuitBuilder
     /**
      * Parses Datalog source code (facts, rules, and checks) into the builder.
      *
-     * @param array<string, int|bool|string|list<int|bool|string>>|null $params Values for `{name}` parameters.
+     * @param array<string, int|bool|string|null|DateTimeInterface|Term|list<int|bool|string|null|DateTimeInterface|Term>>|null $params Values for `{name}` parameters.
      * @param array<string, PublicKey>|null $scope_params Public keys for trust scope parameters.
      *
      * @throws TermException If the source cannot be parsed or a parameter cannot be applied.
@@ -123,6 +124,20 @@ class BiscuitBuilder
         );
     }
 
+    /**
+     * Attaches a free-form context string to the authority block, for
+     * instance a request id. It is not used during authorization and can be
+     * read back with {@see Biscuit::blockContext()}.
+     *
+     * @throws BuilderStateException If the builder has already been consumed.
+     */
+    public function setContext(string $context): void
+    {
+        throw new Error(
+            'Biscuit\Auth\BiscuitBuilder::setContext() should be implemented by the biscuit_php extension.',
+        );
+    }
+
     /**
      * Returns the builder content as Datalog source code.
      *
@@ -0,0 +1,104 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Biscuit\Auth;
+
+use Error;
+
+/**
+ * A structured view of one block of a token, exposed by
+ * {@see Biscuit::getBlock()}.
+ */
+class Block
+{
+    /**
+     * Instances are obtained through {@see Biscuit::getBlock()}.
+     */
+    private function __construct() {}
+
+    /**
+     * Returns the index of the block in the token, 0 being the authority
+     * block.
+     */
+    public function getIndex(): int
+    {
+        throw new Error('Biscuit\Auth\Block::getIndex() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the facts declared in the block. Each fact reports the block
+     * as its origin (see {@see Fact::getOrigins()}).
+     *
+     * @return list<Fact>
+     */
+    public function getFacts(): array
+    {
+        throw new Error('Biscuit\Auth\Block::getFacts() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the rules declared in the block.
+     *
+     * @return list<Rule>
+     */
+    public function getRules(): array
+    {
+        throw new Error('Biscuit\Auth\Block::getRules() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the checks declared in the block.
+     *
+     * @return list<Check>
+     */
+    public function getChecks(): array
+    {
+        throw new Error('Biscuit\Auth\Block::getChecks() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the free-form context string attached to the block, or null
+     * when it has none.
+     */
+    public function getContext(): ?string
+    {
+        throw new Error('Biscuit\Auth\Block::getContext() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the Datalog version the block was serialized with.
+     */
+    public function getVersion(): int
+    {
+        throw new Error('Biscuit\Auth\Block::getVersion() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the public key of the third party that signed the block, or
+     * null for first-party blocks.
+     */
+    public function getExternalKey(): ?PublicKey
+    {
+        throw new Error('Biscuit\Auth\Block::getExternalKey() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the algorithm of the key that signed the block: the root key
+     * for the authority block, otherwise the key generated along with the
+     * previous block.
+     */
+    public function getAlgorithm(): Algorithm
+    {
+        throw new Error('Biscuit\Auth\Block::getAlgorithm() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the hex-encoded revocation id of the block, as listed by
+     * {@see Biscuit::revocationIds()}.
+     */
+    public function getRevocationId(): string
+    {
+        throw new Error('Biscuit\Auth\Block::getRevocationId() should be implemented by the biscuit_php extension.');
+    }
+}
@@ -9,6 +9,7 @@ use Biscuit\Exception\CheckException;
 use Biscuit\Exception\FactException;
 use Biscuit\Exception\RuleException;
 use Biscuit\Exception\TermException;
+use DateTimeInterface;
 use Error;
 
 /**
@@ -28,7 +29,7 @@ class BlockBuilder
      * Creates a builder, optionally seeded with Datalog source code
      * (equivalent to calling {@see BlockBuilder::addCode()}).
      *
-     * @param array<string, int|bool|string|list<int|bool|string>>|null $params Values for `{name}` parameters.
+     * @param array<string, int|bool|string|null|DateTimeInterface|Term|list<int|bool|string|null|DateTimeInterface|Term>>|null $params Values for `{name}` parameters.
      * @param array<string, PublicKey>|null $scope_params Public keys for trust scope parameters.
      *
      * @throws TermException If the source cannot be parsed or a parameter cannot be applied.
@@ -68,7 +69,7 @@ class BlockBuilder
     /**
      * Parses Datalog source code (facts, rules, and checks) into the builder.
      *
-     * @param array<string, int|bool|string|list<int|bool|string>>|null $params Values for `{name}` parameters.
+     * @param array<string, int|bool|string|null|DateTimeInterface|Term|list<int|bool|string|null|DateTimeInterface|Term>>|null $params Values for `{name}` parameters.
      * @param array<string, PublicKey>|null $scope_params Public keys for trust scope parameters.
      *
      * @throws TermException If the source cannot be parsed or a parameter cannot be applied.
@@ -92,6 +93,20 @@ class BlockBuilder
         throw new Error('Biscuit\Auth\BlockBuilder::merge() should be implemented by the biscuit_php extension.');
     }
 
+    /**
+     * Attaches a free-form context string to the block, for instance the id
+     * of the request that produced the attenuation. It is not used during
+     * authorization and can be read back with {@see Biscuit::blockContext()}.
+     *
+     * @throws BuilderStateException If the builder has already been consumed.
+     */
+    public function setContext(string $context): void
+    {
+        throw new Error(
+            'Biscuit\Auth\BlockBuilder::setContext() should be implemented by the biscuit_php extension.',
+        );
+    }
+
     /**
      * Returns the builder content as Datalog source code.
      *
@@ -7,6 +7,7 @@ namespace Biscuit\Auth;
 use Biscuit\Exception\CheckException;
 use Biscuit\Exception\ScopeException;
 use Biscuit\Exception\TermException;
+use DateTimeInterface;
 use Error;
 
 /**
@@ -28,11 +29,11 @@ class Check
      * Parses a check from Datalog source code.
      *
      * @param non-empty-string $source
-     * @param array<string, int|bool|string|list<int|bool|string>>|null $params Values for `{name}` parameters.
+     * @param array<string, int|bool|string|null|DateTimeInterface|Term|list<int|bool|string|null|DateTimeInterface|Term>>|null $params Values for `{name}` parameters.
      * @param array<string, PublicKey>|null $scope_params Public keys for trust scope parameters.
      *
      * @throws CheckException If the source is not a valid check.
-     * @throws TermException If a parameter value has an unsupported type (e.g. null).
+     * @throws TermException If a parameter value has an unsupported type (e.g. an object).
      * @throws ScopeException If a scope parameter cannot be applied.
      */
     public function __construct(string $source, ?array $params = null, ?array $scope_params = null)
@@ -43,9 +44,9 @@ class Check
     /**
      * Binds the `{$name}` parameter to a value.
      *
-     * @param int|bool|string|list<int|bool|string> $value
+     * @param int|bool|string|null|DateTimeInterface|Term|list<int|bool|string|null|DateTimeInterface|Term> $value
      *
-     * @throws TermException If the value has an unsupported type (e.g. null).
+     * @throws TermException If the value has an unsupported type (e.g. an object).
      */
     public function set(string $name, mixed $value): void
     {
@@ -62,6 +63,19 @@ class Check
         throw new Error('Biscuit\Auth\Check::setScope() should be implemented by the biscuit_php extension.');
     }
 
+    /**
+     * Returns the blocks this check came from when it was obtained from
+     * {@see Authorizer::getChecks()}: block indexes (0 being the authority block)
+     * and `'authorizer'` for the authorizer itself. Returns null for a
+     * check created from source code.
+     *
+     * @return list<int|'authorizer'>|null
+     */
+    public function getOrigins(): ?array
+    {
+        throw new Error('Biscuit\Auth\Check::getOrigins() should be implemented by the biscuit_php extension.');
+    }
+
     /**
      * Returns the check as Datalog source code.
      */
@@ -69,4 +83,25 @@ class Check
     {
         throw new Error('Biscuit\Auth\Check::__toString() should be implemented by the biscuit_php extension.');
     }
+
+    /**
+     * @return array{source: string}
+     */
+    public function __serialize(): array
+    {
+        throw new Error('Biscuit\Auth\Check::__serialize() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Restores the check from its Datalog source, with parameters already applied.
+     * Origins are not kept.
+     *
+     * @param array{source: string} $data
+     *
+     * @throws CheckException If the payload is malformed or the source does not parse.
+     */
+    public function __unserialize(array $data): void
+    {
+        throw new Error('Biscuit\Auth\Check::__unserialize() should be implemented by the biscuit_php extension.');
+    }
 }
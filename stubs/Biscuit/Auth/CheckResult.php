@@ -0,0 +1,63 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Biscuit\Auth;
+
+use Error;
+
+/**
+ * The outcome of a single check, exposed by
+ * {@see AuthorizationResult::getChecks()}.
+ */
+class CheckResult
+{
+    /**
+     * Instances are created by the extension during authorization.
+     */
+    private function __construct() {}
+
+    /**
+     * Returns where the check was declared: in a token block or in the
+     * authorizer.
+     *
+     * @return 'block'|'authorizer'
+     */
+    public function getOrigin(): string
+    {
+        throw new Error('Biscuit\Auth\CheckResult::getOrigin() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the index of the block declaring the check, or null when the
+     * check comes from the authorizer.
+     */
+    public function getBlockId(): ?int
+    {
+        throw new Error('Biscuit\Auth\CheckResult::getBlockId() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the index of the check within its block or the authorizer.
+     */
+    public function getCheckId(): int
+    {
+        throw new Error('Biscuit\Auth\CheckResult::getCheckId() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the Datalog source of the check.
+     */
+    public function getRule(): string
+    {
+        throw new Error('Biscuit\Auth\CheckResult::getRule() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns whether the check succeeded.
+     */
+    public function hasPassed(): bool
+    {
+        throw new Error('Biscuit\Auth\CheckResult::hasPassed() should be implemented by the biscuit_php extension.');
+    }
+}
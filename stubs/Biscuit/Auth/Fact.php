@@ -6,13 +6,17 @@ namespace Biscuit\Auth;
 
 use Biscuit\Exception\FactException;
 use Biscuit\Exception\TermException;
+use DateTimeInterface;
 use Error;
 
 /**
  * A Datalog fact, such as `user("alice")`.
  *
  * Parameters written as `{name}` in the source are substituted from the
- * `$params` map or bound later with {@see Fact::set()}.
+ * `$params` map or bound later with {@see Fact::set()}. PHP lists are
+ * bound as Datalog sets (lists of integers in 0..255 as bytes), arrays with
+ * keys as maps and null as `null`; use {@see Term::array()} or
+ * {@see Term::map()} to bind an array, or a list as a map.
  *
  * ```php
  * $fact = new Fact('user({name})', ['name' => 'alice']);
@@ -27,10 +31,10 @@ class Fact
      * Parses a fact from Datalog source code.
      *
      * @param non-empty-string $source
-     * @param array<string, int|bool|string|list<int|bool|string>>|null $params Values for `{name}` parameters.
+     * @param array<string, int|bool|string|null|DateTimeInterface|Term|list<int|bool|string|null|DateTimeInterface|Term>>|null $params Values for `{name}` parameters.
      *
      * @throws FactException If the source is not a valid fact.
-     * @throws TermException If a parameter value has an unsupported type (e.g. null).
+     * @throws TermException If a parameter value has an unsupported type (e.g. an object).
      */
     public function __construct(string $source, ?array $params = null)
     {
@@ -40,9 +44,9 @@ class Fact
     /**
      * Binds the `{$name}` parameter to a value.
      *
-     * @param int|bool|string|list<int|bool|string> $value
+     * @param int|bool|string|null|DateTimeInterface|Term|list<int|bool|string|null|DateTimeInterface|Term> $value
      *
-     * @throws TermException If the value has an unsupported type (e.g. null).
+     * @throws TermException If the value has an unsupported type (e.g. an object).
      */
     public function set(string $name, mixed $value): void
     {
@@ -57,6 +61,51 @@ class Fact
         throw new Error('Biscuit\Auth\Fact::name() should be implemented by the biscuit_php extension.');
     }
 
+    /**
+     * Returns the fact's terms as PHP values, in order.
+     *
+     * Integers, strings, booleans and null map to their PHP equivalents,
+     * byte strings (`hex:...`) to binary strings, sets and arrays to lists,
+     * and dates to {@see \DateTimeImmutable} in UTC.
+     *
+     * ```php
+     * $fact = new Fact('user("alice", 42)');
+     * [$name, $id] = $fact->getTerms(); // 'alice', 42
+     * ```
+     *
+     * @return list<mixed>
+     *
+     * @throws TermException If the fact still contains an unbound parameter.
+     */
+    public function getTerms(): array
+    {
+        throw new Error('Biscuit\Auth\Fact::getTerms() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the fact's terms as {@see Term} objects, in order, keeping
+     * their Datalog type.
+     *
+     * @return list<Term>
+     */
+    public function getTypedTerms(): array
+    {
+        throw new Error('Biscuit\Auth\Fact::getTypedTerms() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the blocks this fact came from when it was obtained from
+     * {@see Authorizer::getFacts()}: block indexes (0 being the authority block)
+     * and `'authorizer'` for the authorizer itself. Returns null for a
+     * fact created from source code.
+     *
+     * @return list<int|'authorizer'>|null
+     */
+    public function getOrigins(): ?array
+    {
+        throw new Error('Biscuit\Auth\Fact::getOrigins() should be implemented by the biscuit_php extension.');
+    }
+
     /**
      * Returns the fact as Datalog source code.
      */
@@ -64,4 +113,25 @@ class Fact
     {
         throw new Error('Biscuit\Auth\Fact::__toString() should be implemented by the biscuit_php extension.');
     }
+
+    /**
+     * @return array{source: string}
+     */
+    public function __serialize(): array
+    {
+        throw new Error('Biscuit\Auth\Fact::__serialize() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Restores the fact from its Datalog source, with parameters already applied.
+     * Origins are not kept.
+     *
+     * @param array{source: string} $data
+     *
+     * @throws FactException If the payload is malformed or the source does not parse.
+     */
+    public function __unserialize(array $data): void
+    {
+        throw new Error('Biscuit\Auth\Fact::__unserialize() should be implemented by the biscuit_php extension.');
+    }
 }
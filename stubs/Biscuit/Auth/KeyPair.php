@@ -4,6 +4,7 @@ declare(strict_types=1);
 
 namespace Biscuit\Auth;
 
+use Biscuit\Exception\PrivateKeyException;
 use Error;
 
 /**
@@ -36,6 +37,27 @@ class KeyPair
         throw new Error('Biscuit\Auth\KeyPair::fromPrivateKey() should be implemented by the biscuit_php extension.');
     }
 
+    /**
+     * Deterministically derives a key pair from a 32-byte secret seed. The same seed and
+     * algorithm always yield byte-identical keys, on every platform.
+     *
+     * - {@see Algorithm::Ed25519}: the seed is used as the private key, as in RFC 8032.
+     * - {@see Algorithm::Secp256r1}: the private scalar is the first
+     *   `SHA-256("biscuit-php/p256-seed/v1" || seed || uint32_be(counter))`, for
+     *   counter = 0, 1, ..., that lies in `[1, n - 1]`.
+     *
+     * The seed must come from a secure source; a low-entropy seed yields a guessable key.
+     *
+     * @param string $seed Exactly 32 bytes (binary string).
+     * @param Algorithm|null $alg Defaults to {@see Algorithm::Ed25519} when null.
+     *
+     * @throws PrivateKeyException If the seed is not 32 bytes long.
+     */
+    public static function fromSeed(string $seed, ?Algorithm $alg = null): KeyPair
+    {
+        throw new Error('Biscuit\Auth\KeyPair::fromSeed() should be implemented by the biscuit_php extension.');
+    }
+
     public function getPublicKey(): PublicKey
     {
         throw new Error('Biscuit\Auth\KeyPair::getPublicKey() should be implemented by the biscuit_php extension.');
@@ -45,4 +67,19 @@ class KeyPair
     {
         throw new Error('Biscuit\Auth\KeyPair::getPrivateKey() should be implemented by the biscuit_php extension.');
     }
+
+    public function getAlgorithm(): Algorithm
+    {
+        throw new Error('Biscuit\Auth\KeyPair::getAlgorithm() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Shows the public key and redacts the private key in `var_dump()` and `print_r()`.
+     *
+     * @return array{publicKey: non-empty-string, privateKey: non-empty-string}
+     */
+    public function __debugInfo(): array
+    {
+        throw new Error('Biscuit\Auth\KeyPair::__debugInfo() should be implemented by the biscuit_php extension.');
+    }
 }
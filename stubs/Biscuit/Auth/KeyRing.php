@@ -0,0 +1,130 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Biscuit\Auth;
+
+use Biscuit\Exception\KeyRingException;
+use Error;
+
+/**
+ * A set of root public keys indexed by root key id, for root key rotation.
+ *
+ * Pass it to {@see Biscuit::fromBase64()}, {@see Biscuit::fromBytes()} or
+ * {@see UnverifiedBiscuit::verify()}: the key is selected from the token's
+ * root key id (see {@see BiscuitBuilder::setRootKeyId()}). Tokens without
+ * a root key id are verified with the default key.
+ *
+ * ```php
+ * $keys = new KeyRing($legacyKey);
+ * $keys->add(1, $previousKey);
+ * $keys->add(2, $currentKey);
+ *
+ * $token = Biscuit::fromBase64($serialized, $keys);
+ * ```
+ */
+class KeyRing
+{
+    /**
+     * Creates a key ring, with an optional default key for tokens that
+     * carry no root key id.
+     */
+    public function __construct(?PublicKey $default = null)
+    {
+        throw new Error('Biscuit\Auth\KeyRing::__construct() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Loads the `*.pem` files of a directory. Each file is named after its
+     * root key id (e.g. `1.pem`), and `default.pem` holds the default key.
+     *
+     * Entries without the `.pem` extension and directories are skipped. The
+     * directory is read through PHP's filesystem functions, so
+     * `open_basedir` and stream wrappers apply.
+     *
+     * @throws KeyRingException If the directory or one of its `.pem` files cannot be read, a file name is not a key id, or a key is invalid.
+     */
+    public static function fromPemDirectory(string $path): KeyRing
+    {
+        throw new Error(
+            'Biscuit\Auth\KeyRing::fromPemDirectory() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Loads a JSON object mapping root key ids to public keys, either in
+     * their `<algorithm>/<hex>` form or as PEM documents. The `"default"`
+     * entry holds the default key.
+     *
+     * ```json
+     * {"default": "ed25519/acdd...", "1": "ed25519/3f5c...", "2": "secp256r1/02a1..."}
+     * ```
+     *
+     * @throws KeyRingException If the document is not such an object or a key is invalid.
+     */
+    public static function fromJson(string $json): KeyRing
+    {
+        throw new Error('Biscuit\Auth\KeyRing::fromJson() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Adds a key, replacing any key already registered under the same id.
+     *
+     * @param non-negative-int $id
+     */
+    public function add(int $id, PublicKey $key): void
+    {
+        throw new Error('Biscuit\Auth\KeyRing::add() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Removes the key registered under an id, returning whether one was.
+     *
+     * @param non-negative-int $id
+     */
+    public function remove(int $id): bool
+    {
+        throw new Error('Biscuit\Auth\KeyRing::remove() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the key registered under an id, or null.
+     *
+     * @param non-negative-int $id
+     */
+    public function get(int $id): ?PublicKey
+    {
+        throw new Error('Biscuit\Auth\KeyRing::get() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * @param non-negative-int $id
+     */
+    public function has(int $id): bool
+    {
+        throw new Error('Biscuit\Auth\KeyRing::has() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the registered key ids, in ascending order.
+     *
+     * @return list<non-negative-int>
+     */
+    public function ids(): array
+    {
+        throw new Error('Biscuit\Auth\KeyRing::ids() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Sets or clears the key used for tokens without a root key id.
+     */
+    public function setDefault(?PublicKey $key): void
+    {
+        throw new Error('Biscuit\Auth\KeyRing::setDefault() should be implemented by the biscuit_php extension.');
+    }
+
+    public function getDefault(): ?PublicKey
+    {
+        throw new Error('Biscuit\Auth\KeyRing::getDefault() should be implemented by the biscuit_php extension.');
+    }
+}
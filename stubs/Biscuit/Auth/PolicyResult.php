@@ -0,0 +1,54 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Biscuit\Auth;
+
+use Error;
+
+/**
+ * The outcome of a single policy, exposed by
+ * {@see AuthorizationResult::getPolicies()}.
+ */
+class PolicyResult
+{
+    /**
+     * Instances are created by the extension during authorization.
+     */
+    private function __construct() {}
+
+    /**
+     * @return 'allow'|'deny'
+     */
+    public function getKind(): string
+    {
+        throw new Error('Biscuit\Auth\PolicyResult::getKind() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the index of the policy, in declaration order.
+     */
+    public function getPolicyId(): int
+    {
+        throw new Error('Biscuit\Auth\PolicyResult::getPolicyId() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the Datalog source of the policy.
+     */
+    public function getCode(): string
+    {
+        throw new Error('Biscuit\Auth\PolicyResult::getCode() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns whether the policy matched.
+     *
+     * Policies are evaluated in order and evaluation stops at the first
+     * match, so policies declared after the matched one return null.
+     */
+    public function isMatched(): ?bool
+    {
+        throw new Error('Biscuit\Auth\PolicyResult::isMatched() should be implemented by the biscuit_php extension.');
+    }
+}
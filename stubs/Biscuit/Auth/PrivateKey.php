@@ -5,6 +5,7 @@ declare(strict_types=1);
 namespace Biscuit\Auth;
 
 use Biscuit\Exception\PrivateKeyException;
+use Biscuit\Exception\PublicKeyException;
 use Error;
 
 /**
@@ -12,6 +13,9 @@ use Error;
  *
  * Its canonical string form is an algorithm-prefixed hex string,
  * e.g. `ed25519-private/473b5189232f3f597b5c2f3f9b0d5e28b1ee4e7cce67ec6b7fbf5984157a6b97`.
+ * Casting to string, `var_dump()` and `print_r()` redact the secret; use
+ * {@see PrivateKey::exportSecret()} to export it on purpose. The key bytes are
+ * zeroized when the object is freed.
  *
  * ```php
  * $privateKey = PrivateKey::generate();
@@ -83,6 +87,39 @@ class PrivateKey
         throw new Error('Biscuit\Auth\PrivateKey::getPublicKey() should be implemented by the biscuit_php extension.');
     }
 
+    public function getAlgorithm(): Algorithm
+    {
+        throw new Error('Biscuit\Auth\PrivateKey::getAlgorithm() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Imports a private key from a JSON Web Key: an `OKP` key on the
+     * `Ed25519` curve or an `EC` key on the `P-256` curve, carrying the
+     * private `d` member. When the public members are present, they must
+     * match the private key.
+     *
+     * @param array{kty: string, crv: string, d: string, x?: string, y?: string}|string $jwk
+     *     The JWK as an array or a JSON string.
+     *
+     * @throws PrivateKeyException If the JWK is malformed or uses an unsupported key type or curve.
+     */
+    public static function fromJwk(array|string $jwk): PrivateKey
+    {
+        throw new Error('Biscuit\Auth\PrivateKey::fromJwk() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Exports the key as a JSON Web Key, including the private `d` member.
+     *
+     * @return array{kty: 'OKP'|'EC', crv: 'Ed25519'|'P-256', x: string, y?: string, d: string}
+     *
+     * @throws PublicKeyException If the derived public key cannot be encoded.
+     */
+    public function toJwk(): array
+    {
+        throw new Error('Biscuit\Auth\PrivateKey::toJwk() should be implemented by the biscuit_php extension.');
+    }
+
     /**
      * Serializes the key to raw bytes.
      *
@@ -93,10 +130,48 @@ class PrivateKey
         throw new Error('Biscuit\Auth\PrivateKey::toBytes() should be implemented by the biscuit_php extension.');
     }
 
+    /**
+     * Serializes the key to a PKCS#8 PEM document, readable by
+     * {@see PrivateKey::fromPem()} and OpenSSL.
+     *
+     * @throws PrivateKeyException If the key cannot be encoded.
+     */
+    public function toPem(): string
+    {
+        throw new Error('Biscuit\Auth\PrivateKey::toPem() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Serializes the key to PKCS#8 DER bytes (binary string), readable by
+     * {@see PrivateKey::fromDer()}.
+     *
+     * @throws PrivateKeyException If the key cannot be encoded.
+     */
+    public function toDer(): string
+    {
+        throw new Error('Biscuit\Auth\PrivateKey::toDer() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Signs arbitrary data with this key, returning the raw signature bytes (binary string).
+     *
+     * Ed25519 keys produce a 64-byte signature; P-256 keys produce an ECDSA/SHA-256
+     * signature in ASN.1 DER encoding (usually 70 to 72 bytes), as biscuit
+     * tokens use. Check it with {@see PublicKey::verify()}.
+     *
+     * @throws PrivateKeyException If the key cannot be used for signing.
+     */
+    public function sign(string $data): string
+    {
+        throw new Error('Biscuit\Auth\PrivateKey::sign() should be implemented by the biscuit_php extension.');
+    }
+
     /**
      * Serializes the key to its algorithm-prefixed hex form (`<algorithm>-private/<hex>`).
      *
      * @return non-empty-string
+     *
+     * @deprecated since 0.6.0, use {@see PrivateKey::exportSecret()} so that exporting the secret is explicit.
      */
     public function toHex(): string
     {
@@ -104,10 +179,61 @@ class PrivateKey
     }
 
     /**
-     * Same representation as {@see PrivateKey::toHex()}.
+     * Exports the secret in its algorithm-prefixed hex form (`<algorithm>-private/<hex>`).
+     * Use it where the secret is meant to leave the process, e.g. to store it in a vault.
+     *
+     * @return non-empty-string
+     */
+    public function exportSecret(): string
+    {
+        throw new Error('Biscuit\Auth\PrivateKey::exportSecret() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Redacted representation that only names the algorithm, e.g. `ed25519-private/[redacted]`.
      */
     public function __toString(): string
     {
         throw new Error('Biscuit\Auth\PrivateKey::__toString() should be implemented by the biscuit_php extension.');
     }
+
+    /**
+     * @return array{key: non-empty-string}
+     */
+    public function __debugInfo(): array
+    {
+        throw new Error('Biscuit\Auth\PrivateKey::__debugInfo() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Opts in to `serialize()` of private keys for the current request. It is off by default,
+     * and reset when the request ends, so a secret does not end up in a cache or session by
+     * accident.
+     */
+    public static function allowSerialization(bool $allow): void
+    {
+        throw new Error(
+            'Biscuit\Auth\PrivateKey::allowSerialization() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * @return array{key: non-empty-string}
+     *
+     * @throws PrivateKeyException If serialization was not enabled with {@see PrivateKey::allowSerialization()}.
+     */
+    public function __serialize(): array
+    {
+        throw new Error('Biscuit\Auth\PrivateKey::__serialize() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * @param array{key: non-empty-string} $data
+     *
+     * @throws PrivateKeyException If the payload is malformed or the key is invalid.
+     */
+    public function __unserialize(array $data): void
+    {
+        throw new Error('Biscuit\Auth\PrivateKey::__unserialize() should be implemented by the biscuit_php extension.');
+    }
 }
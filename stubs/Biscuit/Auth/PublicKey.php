@@ -64,6 +64,40 @@ class PublicKey
         throw new Error('Biscuit\Auth\PublicKey::fromDer() should be implemented by the biscuit_php extension.');
     }
 
+    /**
+     * Imports a public key from a JSON Web Key: an `OKP` key on the
+     * `Ed25519` curve or an `EC` key on the `P-256` curve. Other members,
+     * such as `kid` or `use`, are ignored.
+     *
+     * ```php
+     * $publicKey = PublicKey::fromJwk([
+     *     'kty' => 'OKP',
+     *     'crv' => 'Ed25519',
+     *     'x' => '11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo',
+     * ]);
+     * ```
+     *
+     * @param array{kty: string, crv: string, x: string, y?: string}|string $jwk The JWK as an array or a JSON string.
+     *
+     * @throws PublicKeyException If the JWK is malformed or uses an unsupported key type or curve.
+     */
+    public static function fromJwk(array|string $jwk): PublicKey
+    {
+        throw new Error('Biscuit\Auth\PublicKey::fromJwk() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Exports the key as a JSON Web Key, with base64url-encoded coordinates.
+     *
+     * @return array{kty: 'OKP'|'EC', crv: 'Ed25519'|'P-256', x: string, y?: string}
+     *
+     * @throws PublicKeyException If the key cannot be encoded.
+     */
+    public function toJwk(): array
+    {
+        throw new Error('Biscuit\Auth\PublicKey::toJwk() should be implemented by the biscuit_php extension.');
+    }
+
     /**
      * Serializes the key to raw bytes.
      *
@@ -74,6 +108,68 @@ class PublicKey
         throw new Error('Biscuit\Auth\PublicKey::toBytes() should be implemented by the biscuit_php extension.');
     }
 
+    public function getAlgorithm(): Algorithm
+    {
+        throw new Error('Biscuit\Auth\PublicKey::getAlgorithm() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Whether both keys use the same algorithm and key material.
+     */
+    public function equals(PublicKey $other): bool
+    {
+        throw new Error('Biscuit\Auth\PublicKey::equals() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Lowercase hex SHA-256 digest of the key's SubjectPublicKeyInfo DER encoding
+     * (see {@see PublicKey::toDer()}), suitable for logs and key rotation dashboards.
+     *
+     * It matches `openssl pkey -pubin -outform DER | sha256sum` for the same key.
+     *
+     * @return non-empty-string
+     *
+     * @throws PublicKeyException If the key cannot be encoded.
+     */
+    public function fingerprint(): string
+    {
+        throw new Error('Biscuit\Auth\PublicKey::fingerprint() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Serializes the key to a SubjectPublicKeyInfo PEM document, readable by
+     * {@see PublicKey::fromPem()} and OpenSSL.
+     *
+     * @throws PublicKeyException If the key cannot be encoded.
+     */
+    public function toPem(): string
+    {
+        throw new Error('Biscuit\Auth\PublicKey::toPem() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Serializes the key to SubjectPublicKeyInfo DER bytes (binary string), readable by
+     * {@see PublicKey::fromDer()}.
+     *
+     * @throws PublicKeyException If the key cannot be encoded.
+     */
+    public function toDer(): string
+    {
+        throw new Error('Biscuit\Auth\PublicKey::toDer() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Checks a signature produced by {@see PrivateKey::sign()} over `$data`.
+     *
+     * Ed25519 signatures are 64 bytes; P-256 signatures are ECDSA/SHA-256 in
+     * ASN.1 DER encoding. A malformed signature, including a raw 64-byte
+     * `r || s` P-256 signature, returns `false`.
+     */
+    public function verify(string $data, string $signature): bool
+    {
+        throw new Error('Biscuit\Auth\PublicKey::verify() should be implemented by the biscuit_php extension.');
+    }
+
     /**
      * Serializes the key to its algorithm-prefixed hex form (`<algorithm>/<hex>`).
      *
@@ -91,4 +187,24 @@ class PublicKey
     {
         throw new Error('Biscuit\Auth\PublicKey::__toString() should be implemented by the biscuit_php extension.');
     }
+
+    /**
+     * @return array{key: non-empty-string}
+     */
+    public function __serialize(): array
+    {
+        throw new Error('Biscuit\Auth\PublicKey::__serialize() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Restores the key from its algorithm-prefixed hex form.
+     *
+     * @param array{key: non-empty-string} $data
+     *
+     * @throws PublicKeyException If the payload is malformed or the key is invalid.
+     */
+    public function __unserialize(array $data): void
+    {
+        throw new Error('Biscuit\Auth\PublicKey::__unserialize() should be implemented by the biscuit_php extension.');
+    }
 }
@@ -7,6 +7,7 @@ namespace Biscuit\Auth;
 use Biscuit\Exception\RuleException;
 use Biscuit\Exception\ScopeException;
 use Biscuit\Exception\TermException;
+use DateTimeInterface;
 use Error;
 
 /**
@@ -25,11 +26,11 @@ class Rule
      * Parses a rule from Datalog source code.
      *
      * @param non-empty-string $source
-     * @param array<string, int|bool|string|list<int|bool|string>>|null $params Values for `{name}` parameters.
+     * @param array<string, int|bool|string|null|DateTimeInterface|Term|list<int|bool|string|null|DateTimeInterface|Term>>|null $params Values for `{name}` parameters.
      * @param array<string, PublicKey>|null $scope_params Public keys for trust scope parameters.
      *
      * @throws RuleException If the source is not a valid rule.
-     * @throws TermException If a parameter value has an unsupported type (e.g. null).
+     * @throws TermException If a parameter value has an unsupported type (e.g. an object).
      * @throws ScopeException If a scope parameter cannot be applied.
      */
     public function __construct(string $source, ?array $params = null, ?array $scope_params = null)
@@ -40,9 +41,9 @@ class Rule
     /**
      * Binds the `{$name}` parameter to a value.
      *
-     * @param int|bool|string|list<int|bool|string> $value
+     * @param int|bool|string|null|DateTimeInterface|Term|list<int|bool|string|null|DateTimeInterface|Term> $value
      *
-     * @throws TermException If the value has an unsupported type (e.g. null).
+     * @throws TermException If the value has an unsupported type (e.g. an object).
      */
     public function set(string $name, mixed $value): void
     {
@@ -59,6 +60,19 @@ class Rule
         throw new Error('Biscuit\Auth\Rule::setScope() should be implemented by the biscuit_php extension.');
     }
 
+    /**
+     * Returns the blocks this rule came from when it was obtained from
+     * {@see Authorizer::getRules()}: block indexes (0 being the authority block)
+     * and `'authorizer'` for the authorizer itself. Returns null for a
+     * rule created from source code.
+     *
+     * @return list<int|'authorizer'>|null
+     */
+    public function getOrigins(): ?array
+    {
+        throw new Error('Biscuit\Auth\Rule::getOrigins() should be implemented by the biscuit_php extension.');
+    }
+
     /**
      * Returns the rule as Datalog source code.
      */
@@ -66,4 +80,25 @@ class Rule
     {
         throw new Error('Biscuit\Auth\Rule::__toString() should be implemented by the biscuit_php extension.');
     }
+
+    /**
+     * @return array{source: string}
+     */
+    public function __serialize(): array
+    {
+        throw new Error('Biscuit\Auth\Rule::__serialize() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Restores the rule from its Datalog source, with parameters already applied.
+     * Origins are not kept.
+     *
+     * @param array{source: string} $data
+     *
+     * @throws RuleException If the payload is malformed or the source does not parse.
+     */
+    public function __unserialize(array $data): void
+    {
+        throw new Error('Biscuit\Auth\Rule::__unserialize() should be implemented by the biscuit_php extension.');
+    }
 }
@@ -0,0 +1,139 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Biscuit\Auth;
+
+use Biscuit\Exception\TermException;
+use DateTimeInterface;
+use Error;
+
+/**
+ * An explicitly typed Datalog term.
+ *
+ * Accepted anywhere a parameter value is accepted (`$params` maps and
+ * `set()` methods), so the Datalog type no longer has to be guessed from
+ * the PHP value: a binary string can be bound as bytes rather than a
+ * string, and a list as an array or a map rather than a set.
+ *
+ * ```php
+ * $fact = new Fact('blob({data}, {tags})', [
+ *     'data' => Term::bytes(random_bytes(16)),
+ *     'tags' => Term::array(['a', 'b']),
+ * ]);
+ *
+ * foreach ($fact->getTypedTerms() as $term) {
+ *     echo $term->getType(), ': ', $term, "\n";
+ * }
+ * ```
+ */
+class Term
+{
+    /**
+     * Instances are obtained through the named constructors.
+     */
+    private function __construct() {}
+
+    public static function string(string $value): Term
+    {
+        throw new Error('Biscuit\Auth\Term::string() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Creates a byte string term (`hex:...`) from a binary string.
+     */
+    public static function bytes(string $value): Term
+    {
+        throw new Error('Biscuit\Auth\Term::bytes() should be implemented by the biscuit_php extension.');
+    }
+
+    public static function integer(int $value): Term
+    {
+        throw new Error('Biscuit\Auth\Term::integer() should be implemented by the biscuit_php extension.');
+    }
+
+    public static function bool(bool $value): Term
+    {
+        throw new Error('Biscuit\Auth\Term::bool() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Creates a date term, truncated to the second. Dates before the Unix
+     * epoch are not supported.
+     */
+    public static function date(DateTimeInterface $value): Term
+    {
+        throw new Error('Biscuit\Auth\Term::date() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Creates a set term; values are deduplicated and ordered.
+     *
+     * @param list<mixed> $values
+     *
+     * @throws TermException If a value has an unsupported type.
+     */
+    public static function set(array $values): Term
+    {
+        throw new Error('Biscuit\Auth\Term::set() should be implemented by the biscuit_php extension.');
+    }
+
+    public static function null(): Term
+    {
+        throw new Error('Biscuit\Auth\Term::null() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Creates an array term, keeping the values in order.
+     *
+     * @param list<mixed> $values
+     *
+     * @throws TermException If a value has an unsupported type.
+     */
+    public static function array(array $values): Term
+    {
+        throw new Error('Biscuit\Auth\Term::array() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Creates a map term. Integer keys become integer map keys, all other
+     * keys become string map keys.
+     *
+     * @param array<array-key, mixed> $values
+     *
+     * @throws TermException If a value has an unsupported type.
+     */
+    public static function map(array $values): Term
+    {
+        throw new Error('Biscuit\Auth\Term::map() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the Datalog type of the term.
+     *
+     * @return 'integer'|'string'|'date'|'bytes'|'bool'|'set'|'null'|'array'|'map'|'variable'|'parameter'
+     */
+    public function getType(): string
+    {
+        throw new Error('Biscuit\Auth\Term::getType() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the term as a PHP value, with the same mapping as
+     * {@see Fact::getTerms()}.
+     *
+     * @throws TermException If the term is a variable or an unbound parameter.
+     */
+    public function getValue(): mixed
+    {
+        throw new Error('Biscuit\Auth\Term::getValue() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the term as Datalog source code.
+     */
+    public function __toString(): string
+    {
+        throw new Error('Biscuit\Auth\Term::__toString() should be implemented by the biscuit_php extension.');
+    }
+}
@@ -4,16 +4,98 @@ declare(strict_types=1);
 
 namespace Biscuit\Auth;
 
+use Biscuit\Exception\Base64Exception;
+use Biscuit\Exception\BytesException;
+use Error;
+
 /**
  * Signed third-party block content.
  *
  * It must be integrated into the token that created the originating
  * {@see ThirdPartyRequest} using {@see Biscuit::appendThirdParty()}.
+ *
+ * ```php
+ * // On the third party's side
+ * $request = ThirdPartyRequest::fromBase64($serializedRequest);
+ * $response = $request->createBlock($privateKey, new BlockBuilder('verified(true)'))->toBase64();
+ *
+ * // Back on the token holder's side
+ * $token = $token->appendThirdParty($thirdPartyPublicKey, ThirdPartyBlock::fromBase64($response));
+ * ```
  */
 class ThirdPartyBlock
 {
     /**
-     * Instances are obtained through {@see ThirdPartyRequest::createBlock()}.
+     * Instances are obtained through {@see ThirdPartyRequest::createBlock()}
+     * or deserialized with {@see ThirdPartyBlock::fromBytes()} and
+     * {@see ThirdPartyBlock::fromBase64()}.
      */
     private function __construct() {}
+
+    /**
+     * Deserializes a third-party block from raw bytes (binary string).
+     *
+     * @throws BytesException If the input is not a valid third-party block, or its payload cannot be decoded.
+     */
+    public static function fromBytes(string $data): ThirdPartyBlock
+    {
+        throw new Error(
+            'Biscuit\Auth\ThirdPartyBlock::fromBytes() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Deserializes a third-party block from a URL-safe base64 string.
+     *
+     * @throws Base64Exception If the input is not a valid third-party block.
+     */
+    public static function fromBase64(string $data): ThirdPartyBlock
+    {
+        throw new Error(
+            'Biscuit\Auth\ThirdPartyBlock::fromBase64() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Serializes the third-party block to raw bytes.
+     *
+     * @return list<int>
+     */
+    public function toBytes(): array
+    {
+        throw new Error('Biscuit\Auth\ThirdPartyBlock::toBytes() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Serializes the third-party block to a URL-safe base64 string.
+     */
+    public function toBase64(): string
+    {
+        throw new Error('Biscuit\Auth\ThirdPartyBlock::toBase64() should be implemented by the biscuit_php extension.');
+    }
+
+    /**
+     * Returns the public key of the third party that signed the block.
+     *
+     * @throws BytesException If the block cannot be decoded.
+     */
+    public function getExternalKey(): PublicKey
+    {
+        throw new Error(
+            'Biscuit\Auth\ThirdPartyBlock::getExternalKey() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Returns the Datalog source of the signed block, printed back from its
+     * encoded contents. This works for deserialized blocks as well.
+     *
+     * @throws BytesException If the block cannot be decoded.
+     */
+    public function getSource(): string
+    {
+        throw new Error(
+            'Biscuit\Auth\ThirdPartyBlock::getSource() should be implemented by the biscuit_php extension.',
+        );
+    }
 }
@@ -4,7 +4,9 @@ declare(strict_types=1);
 
 namespace Biscuit\Auth;
 
+use Biscuit\Exception\Base64Exception;
 use Biscuit\Exception\BuilderStateException;
+use Biscuit\Exception\BytesException;
 use Biscuit\Exception\ThirdPartyException;
 use Error;
 
@@ -12,6 +14,9 @@ use Error;
  * A request sent to a third party so it can add a signed block to a token
  * without seeing its content.
  *
+ * Requests and the resulting blocks can be serialized to travel between
+ * services.
+ *
  * ```php
  * $request = $token->thirdPartyRequest();
  *
@@ -25,18 +30,102 @@ use Error;
 class ThirdPartyRequest
 {
     /**
-     * Instances are obtained through {@see Biscuit::thirdPartyRequest()}.
+     * Instances are obtained through {@see Biscuit::thirdPartyRequest()}
+     * or deserialized with {@see ThirdPartyRequest::fromBytes()} and
+     * {@see ThirdPartyRequest::fromBase64()}.
      */
     private function __construct() {}
 
+    /**
+     * Deserializes a third-party request from raw bytes (binary string).
+     *
+     * @throws BytesException If the input is not a valid third-party request.
+     */
+    public static function fromBytes(string $data): ThirdPartyRequest
+    {
+        throw new Error(
+            'Biscuit\Auth\ThirdPartyRequest::fromBytes() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Deserializes a third-party request from a URL-safe base64 string.
+     *
+     * @throws Base64Exception If the input is not a valid third-party request.
+     */
+    public static function fromBase64(string $data): ThirdPartyRequest
+    {
+        throw new Error(
+            'Biscuit\Auth\ThirdPartyRequest::fromBase64() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Serializes the third-party request to raw bytes.
+     *
+     * @return list<int>
+     *
+     * @throws BytesException If the third-party request cannot be serialized.
+     */
+    public function toBytes(): array
+    {
+        throw new Error(
+            'Biscuit\Auth\ThirdPartyRequest::toBytes() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Serializes the third-party request to a URL-safe base64 string.
+     *
+     * @throws Base64Exception If the third-party request cannot be serialized.
+     */
+    public function toBase64(): string
+    {
+        throw new Error(
+            'Biscuit\Auth\ThirdPartyRequest::toBase64() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Returns the signature of the token's last block, hex-encoded. The
+     * third-party block created from this request is bound to it, so it
+     * can only be appended to the token the request came from.
+     *
+     * Requests do not expose the public keys of the token's previous
+     * blocks: since biscuit v3 they are only kept in legacy fields that
+     * are no longer filled in.
+     *
+     * @throws BytesException If the request cannot be decoded.
+     */
+    public function getPreviousSignature(): string
+    {
+        throw new Error(
+            'Biscuit\Auth\ThirdPartyRequest::getPreviousSignature() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Returns the hex-encoded SHA-256 digest of the serialized request, a
+     * stable identifier suitable for logging which requests were signed.
+     *
+     * @throws BytesException If the request cannot be serialized.
+     */
+    public function getDigest(): string
+    {
+        throw new Error(
+            'Biscuit\Auth\ThirdPartyRequest::getDigest() should be implemented by the biscuit_php extension.',
+        );
+    }
+
     /**
      * Creates a {@see ThirdPartyBlock} signed with the third party's
      * private key.
      *
-     * The request is consumed by this call: calling it a second time throws
-     * a {@see BuilderStateException}.
+     * The request is left untouched, so it can be used to sign several
+     * alternative blocks; each of them can be appended to the originating
+     * token.
      *
-     * @throws BuilderStateException If the request or $block has already been consumed.
+     * @throws BuilderStateException If $block has already been consumed.
      * @throws ThirdPartyException If the block cannot be created.
      */
     public function createBlock(PrivateKey $private_key, BlockBuilder $block): ThirdPartyBlock
@@ -7,8 +7,12 @@ namespace Biscuit\Auth;
 use Biscuit\Exception\Base64Exception;
 use Biscuit\Exception\BlockAppendException;
 use Biscuit\Exception\BuilderStateException;
+use Biscuit\Exception\BytesException;
+use Biscuit\Exception\KeyRingException;
 use Biscuit\Exception\SignatureException;
 use Biscuit\Exception\SnapshotException;
+use Biscuit\Exception\ThirdPartyBlockAppendException;
+use Biscuit\Exception\ThirdPartyException;
 use Error;
 
 /**
@@ -28,10 +32,24 @@ use Error;
 class UnverifiedBiscuit
 {
     /**
-     * Instances are obtained through {@see UnverifiedBiscuit::fromBase64()}.
+     * Instances are obtained through {@see UnverifiedBiscuit::fromBase64()}
+     * or {@see UnverifiedBiscuit::fromBytes()}.
      */
     private function __construct() {}
 
+    /**
+     * Deserializes a token from raw bytes (binary string), without verifying
+     * its signatures.
+     *
+     * @throws BytesException If the input is not a valid token.
+     */
+    public static function fromBytes(string $data): UnverifiedBiscuit
+    {
+        throw new Error(
+            'Biscuit\Auth\UnverifiedBiscuit::fromBytes() should be implemented by the biscuit_php extension.',
+        );
+    }
+
     /**
      * Deserializes a token from a URL-safe base64 string, without verifying
      * its signatures.
@@ -45,6 +63,32 @@ class UnverifiedBiscuit
         );
     }
 
+    /**
+     * Serializes the token to raw bytes.
+     *
+     * @return list<int>
+     *
+     * @throws BytesException If the token cannot be serialized.
+     */
+    public function toBytes(): array
+    {
+        throw new Error(
+            'Biscuit\Auth\UnverifiedBiscuit::toBytes() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Serializes the token to a URL-safe base64 string.
+     *
+     * @throws Base64Exception If the token cannot be serialized.
+     */
+    public function toBase64(): string
+    {
+        throw new Error(
+            'Biscuit\Auth\UnverifiedBiscuit::toBase64() should be implemented by the biscuit_php extension.',
+        );
+    }
+
     /**
      * Returns the optional root key identifier stored in the token, a hint
      * for public key selection during verification
@@ -83,7 +127,7 @@ class UnverifiedBiscuit
      * Adds an attenuation block and returns the new token; the original
      * token is left unchanged.
      *
-     * @throws BlockAppendException If the block cannot be appended.
+     * @throws BlockAppendException If the block cannot be appended, e.g. because the token is sealed.
      * @throws BuilderStateException If $block has already been consumed.
      */
     public function append(BlockBuilder $block): UnverifiedBiscuit
@@ -91,6 +135,35 @@ class UnverifiedBiscuit
         throw new Error('Biscuit\Auth\UnverifiedBiscuit::append() should be implemented by the biscuit_php extension.');
     }
 
+    /**
+     * Adds a third-party block created by {@see ThirdPartyRequest::createBlock()}
+     * and returns the new token.
+     *
+     * @param PublicKey $external_key The third party's public key.
+     *
+     * @throws BlockAppendException If the token is sealed.
+     * @throws ThirdPartyBlockAppendException If the block cannot be appended.
+     */
+    public function appendThirdParty(PublicKey $external_key, ThirdPartyBlock $block): UnverifiedBiscuit
+    {
+        throw new Error(
+            'Biscuit\Auth\UnverifiedBiscuit::appendThirdParty() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Creates the request to send to a third party so it can produce a
+     * signed block for this token.
+     *
+     * @throws ThirdPartyException If the request cannot be created.
+     */
+    public function thirdPartyRequest(): ThirdPartyRequest
+    {
+        throw new Error(
+            'Biscuit\Auth\UnverifiedBiscuit::thirdPartyRequest() should be implemented by the biscuit_php extension.',
+        );
+    }
+
     /**
      * Returns one hex-encoded revocation identifier per block, in order.
      *
@@ -106,14 +179,86 @@ class UnverifiedBiscuit
         );
     }
 
+    /**
+     * Returns the external public key of every block, in order: null for
+     * first-party blocks, the signing key for third-party blocks.
+     *
+     * @return list<PublicKey|null>
+     */
+    public function externalKeys(): array
+    {
+        throw new Error(
+            'Biscuit\Auth\UnverifiedBiscuit::externalKeys() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Returns the external public key of the given block, or null when the
+     * block has none.
+     *
+     * @throws SnapshotException If the block index is out of range.
+     */
+    public function blockExternalKey(int $index): ?PublicKey
+    {
+        throw new Error(
+            'Biscuit\Auth\UnverifiedBiscuit::blockExternalKey() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Returns whether the token is sealed, i.e. no block can be appended.
+     *
+     * @throws BytesException If the token cannot be serialized.
+     */
+    public function isSealed(): bool
+    {
+        throw new Error(
+            'Biscuit\Auth\UnverifiedBiscuit::isSealed() should be implemented by the biscuit_php extension.',
+        );
+    }
+
     /**
      * Checks the token signatures against the root public key and converts
      * it to a {@see Biscuit} usable for authorization.
      *
-     * @throws SignatureException If signature verification fails.
+     * `$root` is either the root public key, a {@see KeyRing}, or a
+     * callable receiving the token's root key id and returning the
+     * matching public key.
+     *
+     * A callable returns null when it does not know the key id; exceptions
+     * it throws are propagated unchanged.
+     *
+     * @param PublicKey|KeyRing|callable(?int): ?PublicKey $root
+     *
+     * @throws SignatureException If no root key is found or signature verification fails.
+     * @throws KeyRingException If the callable returns something other than a PublicKey or null.
      */
-    public function verify(PublicKey $root): Biscuit
+    public function verify(PublicKey|KeyRing|callable $root): Biscuit
     {
         throw new Error('Biscuit\Auth\UnverifiedBiscuit::verify() should be implemented by the biscuit_php extension.');
     }
+
+    /**
+     * @return array{token: string}
+     */
+    public function __serialize(): array
+    {
+        throw new Error(
+            'Biscuit\Auth\UnverifiedBiscuit::__serialize() should be implemented by the biscuit_php extension.',
+        );
+    }
+
+    /**
+     * Restores a token from {@see UnverifiedBiscuit::__serialize()} output, without verifying it.
+     *
+     * @param array{token: string} $data
+     *
+     * @throws BytesException If the payload is malformed or the token is invalid.
+     */
+    public function __unserialize(array $data): void
+    {
+        throw new Error(
+            'Biscuit\Auth\UnverifiedBiscuit::__unserialize() should be implemented by the biscuit_php extension.',
+        );
+    }
 }
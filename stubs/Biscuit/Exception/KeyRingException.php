@@ -0,0 +1,14 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Biscuit\Exception;
+
+/**
+ * Thrown when a {@see \Biscuit\Auth\KeyRing} cannot be loaded
+ * (fromPemDirectory, fromJson).
+ */
+class KeyRingException extends KeyException
+{
+    private function __construct() {}
+}
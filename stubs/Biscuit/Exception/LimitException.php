@@ -0,0 +1,37 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Biscuit\Exception;
+
+use Error;
+
+/**
+ * Thrown when a Datalog evaluation exceeds a run limit, either the
+ * defaults or those set with {@see \Biscuit\Auth\AuthorizerBuilder::setLimits()}
+ * and {@see \Biscuit\Auth\Authorizer::authorizeWithLimits()}.
+ *
+ * ```php
+ * try {
+ *     $authorizer->authorizeWithLimits(1000, 100, 1000);
+ * } catch (LimitException $e) {
+ *     $tripped = $e->getLimit(); // 'max_facts', 'max_iterations' or 'max_time'
+ * }
+ * ```
+ */
+class LimitException extends BiscuitException
+{
+    private function __construct() {}
+
+    /**
+     * Returns the limit that was exceeded.
+     *
+     * @return 'max_facts'|'max_iterations'|'max_time'
+     */
+    public function getLimit(): string
+    {
+        throw new Error(
+            'Biscuit\Exception\LimitException::getLimit() should be implemented by the biscuit_php extension.',
+        );
+    }
+}
@@ -4,8 +4,12 @@ declare(strict_types=1);
 
 namespace Biscuit\Tests;
 
+use Biscuit\Auth\AuthorizerBuilder;
 use Biscuit\Auth\Check;
+use Biscuit\Exception\AuthorizationException;
 use Biscuit\Exception\CheckException;
+use DateTimeImmutable;
+use DateTimeZone;
 use PHPUnit\Framework\Attributes\Test;
 use PHPUnit\Framework\TestCase;
 
@@ -19,6 +23,29 @@ class CheckTest extends TestCase
         static::assertSame('check if resource("uuid"), operation("read") or admin("authority")', (string) $check);
     }
 
+    #[Test]
+    public function dateTimeParameterBecomesDate(): void
+    {
+        $expiry = new DateTimeImmutable('2030-06-01 12:00:00', new DateTimeZone('Europe/Paris'));
+
+        $check = new Check('check if time($t), $t < {expiry}', ['expiry' => $expiry]);
+
+        static::assertSame('check if time($t), $t < 2030-06-01T10:00:00Z', (string) $check);
+    }
+
+    #[Test]
+    public function dateTimeParameterIsEnforcedByAuthorizer(): void
+    {
+        $builder = new AuthorizerBuilder('time(2030-01-01T00:00:00Z); allow if true');
+        $builder->addCheck(new Check('check if time($t), $t < {expiry}', [
+            'expiry' => new DateTimeImmutable('2029-01-01T00:00:00Z'),
+        ]));
+
+        $this->expectException(AuthorizationException::class);
+
+        $builder->buildUnauthenticated()->authorize();
+    }
+
     #[Test]
     public function badCheckThrowsCheckException(): void
     {
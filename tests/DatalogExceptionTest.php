@@ -16,6 +16,7 @@ use Biscuit\Exception\ScopeException;
 use Biscuit\Exception\TermException;
 use PHPUnit\Framework\Attributes\Test;
 use PHPUnit\Framework\TestCase;
+use stdClass;
 
 class DatalogExceptionTest extends TestCase
 {
@@ -87,7 +88,7 @@ class DatalogExceptionTest extends TestCase
     {
         try {
             $fact = new Fact('user({id})');
-            $fact->set('id', null);
+            $fact->set('id', new stdClass());
             static::fail('expected TermException');
         } catch (TermException $e) {
             static::assertInstanceOf(DatalogException::class, $e);
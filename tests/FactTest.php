@@ -4,8 +4,15 @@ declare(strict_types=1);
 
 namespace Biscuit\Tests;
 
+use Biscuit\Auth\AuthorizerBuilder;
 use Biscuit\Auth\Fact;
+use Biscuit\Auth\Rule;
+use Biscuit\Auth\Term;
 use Biscuit\Exception\FactException;
+use Biscuit\Exception\TermException;
+use DateTime;
+use DateTimeImmutable;
+use DateTimeZone;
 use PHPUnit\Framework\Attributes\Test;
 use PHPUnit\Framework\TestCase;
 
@@ -31,6 +38,101 @@ class FactTest extends TestCase
         static::assertSame('user("bob")', (string) $fact);
     }
 
+    #[Test]
+    public function getTermsReturnsNativeValues(): void
+    {
+        $fact = new Fact('data(42, "alice", true, hex:deadbeef, [1, 2], 2024-01-01T00:00:00Z, null)');
+
+        [$int, $string, $bool, $bytes, $set, $date, $null] = $fact->getTerms();
+
+        static::assertSame(42, $int);
+        static::assertSame('alice', $string);
+        static::assertTrue($bool);
+        static::assertSame(hex2bin('deadbeef'), $bytes);
+        static::assertSame([1, 2], $set);
+        static::assertInstanceOf(DateTimeImmutable::class, $date);
+        static::assertSame('2024-01-01T00:00:00+00:00', $date->format(DATE_ATOM));
+        static::assertNull($null);
+    }
+
+    #[Test]
+    public function dateTermRoundTripsAsUtcDateTimeImmutable(): void
+    {
+        $issued = new DateTime('2024-03-10 08:30:00', new DateTimeZone('America/New_York'));
+
+        [$date] = (new Fact('issued({at})', ['at' => $issued]))->getTerms();
+
+        static::assertInstanceOf(DateTimeImmutable::class, $date);
+        static::assertSame($issued->getTimestamp(), $date->getTimestamp());
+        static::assertSame('+00:00', $date->format('P'));
+    }
+
+    #[Test]
+    public function listParameterBindsAsSet(): void
+    {
+        $fact = new Fact('tags({tags})', ['tags' => ['b', 'a', 'b']]);
+
+        static::assertSame('tags({"a", "b"})', (string) $fact);
+        static::assertSame('set', $fact->getTypedTerms()[0]->getType());
+    }
+
+    #[Test]
+    public function arrayTermBindsAsArray(): void
+    {
+        $fact = new Fact('tags({tags})', ['tags' => Term::array(['b', 'a', 'b'])]);
+
+        static::assertSame('tags(["b", "a", "b"])', (string) $fact);
+        static::assertSame([['b', 'a', 'b']], $fact->getTerms());
+    }
+
+    #[Test]
+    public function mapTermBindsAsMap(): void
+    {
+        $fact = new Fact('attrs({attrs})', ['attrs' => Term::map(['owner' => 'alice', 7 => true])]);
+
+        static::assertSame('map', $fact->getTypedTerms()[0]->getType());
+
+        [$attrs] = $fact->getTerms();
+        static::assertSame('alice', $attrs['owner']);
+        static::assertTrue($attrs[7]);
+    }
+
+    #[Test]
+    public function keyedArrayParameterBindsAsMap(): void
+    {
+        $fact = new Fact('attrs({attrs})', ['attrs' => ['owner' => 'alice', 'level' => 3]]);
+
+        static::assertSame('map', $fact->getTypedTerms()[0]->getType());
+        static::assertSame([['level' => 3, 'owner' => 'alice']], $fact->getTerms());
+    }
+
+    #[Test]
+    public function nullParameterBindsAsNull(): void
+    {
+        $fact = new Fact('parent({id})', ['id' => null]);
+
+        static::assertSame('parent(null)', (string) $fact);
+        static::assertSame([null], $fact->getTerms());
+    }
+
+    #[Test]
+    public function getTermsAfterQuery(): void
+    {
+        $authorizer = (new AuthorizerBuilder('user("alice"); allow if true'))->buildUnauthenticated();
+
+        $facts = $authorizer->query(new Rule('u($name) <- user($name)'));
+
+        static::assertSame(['alice'], $facts[0]->getTerms());
+    }
+
+    #[Test]
+    public function getTermsWithUnboundParameterThrowsTermException(): void
+    {
+        $this->expectException(TermException::class);
+
+        (new Fact('user({id})'))->getTerms();
+    }
+
     #[Test]
     public function badFactThrowsFactException(): void
     {
@@ -4,6 +4,8 @@ declare(strict_types=1);
 
 namespace Biscuit\Tests;
 
+use Biscuit\Auth\Algorithm;
+use Biscuit\Auth\KeyPair;
 use Biscuit\Auth\PrivateKey;
 use Biscuit\Auth\PublicKey;
 use Biscuit\Exception\BiscuitException;
@@ -44,4 +46,46 @@ class KeyExceptionTest extends TestCase
 
         new PrivateKey('not-a-valid-private-key');
     }
+
+    #[Test]
+    public function unsupportedJwkThrowsPublicKeyException(): void
+    {
+        $this->expectException(PublicKeyException::class);
+
+        PublicKey::fromJwk(['kty' => 'RSA', 'n' => 'AQAB', 'e' => 'AQAB']);
+    }
+
+    #[Test]
+    public function invalidJwkJsonThrowsPublicKeyException(): void
+    {
+        $this->expectException(PublicKeyException::class);
+
+        PublicKey::fromJwk('{not json');
+    }
+
+    #[Test]
+    public function jwkWithMisSizedCoordinatesThrowsPublicKeyException(): void
+    {
+        $jwk = (new KeyPair(Algorithm::Secp256r1))->getPublicKey()->toJwk();
+        $decode = static fn (string $value): string => base64_decode(strtr($value, '-_', '+/'), true) ?: '';
+        $encode = static fn (string $value): string => rtrim(strtr(base64_encode($value), '+/', '-_'), '=');
+
+        // Same 64 bytes once concatenated, split at the wrong place
+        $x = $decode($jwk['x']);
+        $y = $decode($jwk['y'] ?? '');
+        $jwk['x'] = $encode($x . $y[0]);
+        $jwk['y'] = $encode(substr($y, 1));
+
+        $this->expectException(PublicKeyException::class);
+
+        PublicKey::fromJwk($jwk);
+    }
+
+    #[Test]
+    public function jwkWithoutPrivateMemberThrowsPrivateKeyException(): void
+    {
+        $this->expectException(PrivateKeyException::class);
+
+        PrivateKey::fromJwk(['kty' => 'OKP', 'crv' => 'Ed25519', 'x' => '11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo']);
+    }
 }
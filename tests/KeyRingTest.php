@@ -0,0 +1,207 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Biscuit\Tests;
+
+use Biscuit\Auth\Biscuit;
+use Biscuit\Auth\BiscuitBuilder;
+use Biscuit\Auth\KeyPair;
+use Biscuit\Auth\KeyRing;
+use Biscuit\Auth\PublicKey;
+use Biscuit\Auth\UnverifiedBiscuit;
+use Biscuit\Exception\Base64Exception;
+use Biscuit\Exception\KeyException;
+use Biscuit\Exception\KeyRingException;
+use PHPUnit\Framework\Attributes\Test;
+use PHPUnit\Framework\TestCase;
+
+class KeyRingTest extends TestCase
+{
+    private const PEM_KEY = "-----BEGIN PUBLIC KEY-----\nMCowBQYDK2VwAyEAqIR/FDhIoNgaC4g2B+miJll8qDV9pVYVGfPuNFz1Omw=\n-----END PUBLIC KEY-----\n";
+    private const PEM_KEY_HEX = 'ed25519/a8847f143848a0d81a0b883607e9a226597ca8357da5561519f3ee345cf53a6c';
+    private const OTHER_KEY_HEX = 'ed25519/41e77e842e5c952a29233992dc8ebbedd2d83291a89bb0eec34457e723a69526';
+
+    #[Test]
+    public function addGetRemove(): void
+    {
+        $key = new PublicKey(self::PEM_KEY_HEX);
+        $ring = new KeyRing();
+
+        static::assertFalse($ring->has(1));
+        static::assertNull($ring->get(1));
+        static::assertNull($ring->getDefault());
+
+        $ring->add(2, $key);
+        $ring->add(1, new PublicKey(self::OTHER_KEY_HEX));
+        static::assertTrue($ring->has(2));
+        static::assertSame(self::PEM_KEY_HEX, $ring->get(2)?->toHex());
+        static::assertSame([1, 2], $ring->ids());
+
+        static::assertTrue($ring->remove(2));
+        static::assertFalse($ring->remove(2));
+        static::assertSame([1], $ring->ids());
+
+        $ring->setDefault($key);
+        static::assertSame(self::PEM_KEY_HEX, $ring->getDefault()?->toHex());
+        $ring->setDefault(null);
+        static::assertNull($ring->getDefault());
+    }
+
+    #[Test]
+    public function selectsKeyByRootKeyId(): void
+    {
+        $oldKp = new KeyPair();
+        $newKp = new KeyPair();
+
+        $ring = new KeyRing($oldKp->getPublicKey());
+        $ring->add(7, $newKp->getPublicKey());
+
+        $builder = new BiscuitBuilder('user("alice")');
+        $builder->setRootKeyId(7);
+        $rotated = $builder->build($newKp->getPrivateKey());
+        $legacy = (new BiscuitBuilder('user("bob")'))->build($oldKp->getPrivateKey());
+
+        static::assertSame(1, Biscuit::fromBase64($rotated->toBase64(), $ring)->blockCount());
+        static::assertSame(1, Biscuit::fromBytes(pack('C*', ...$rotated->toBytes()), $ring)->blockCount());
+        static::assertSame(1, Biscuit::fromBase64($legacy->toBase64(), $ring)->blockCount());
+        static::assertSame(1, UnverifiedBiscuit::fromBase64($rotated->toBase64())->verify($ring)->blockCount());
+    }
+
+    #[Test]
+    public function unknownRootKeyIdFails(): void
+    {
+        $kp = new KeyPair();
+        $builder = new BiscuitBuilder('user("alice")');
+        $builder->setRootKeyId(3);
+        $token = $builder->build($kp->getPrivateKey())->toBase64();
+
+        $this->expectException(Base64Exception::class);
+
+        Biscuit::fromBase64($token, new KeyRing($kp->getPublicKey()));
+    }
+
+    #[Test]
+    public function fromJsonAcceptsHexAndPemKeys(): void
+    {
+        $ring = KeyRing::fromJson((string) json_encode([
+            'default' => self::OTHER_KEY_HEX,
+            '1' => self::PEM_KEY,
+            '2' => self::OTHER_KEY_HEX,
+        ]));
+
+        static::assertSame([1, 2], $ring->ids());
+        static::assertSame(self::PEM_KEY_HEX, $ring->get(1)?->toHex());
+        static::assertSame(self::OTHER_KEY_HEX, $ring->getDefault()?->toHex());
+    }
+
+    #[Test]
+    public function fromJsonRejectsInvalidDocuments(): void
+    {
+        $documents = ['not json', '"ed25519/00"', '{"1": "not a key"}', '{"first": "' . self::PEM_KEY_HEX . '"}'];
+
+        foreach ($documents as $json) {
+            try {
+                KeyRing::fromJson($json);
+                static::fail("expected KeyRingException for {$json}");
+            } catch (KeyRingException $e) {
+                static::assertInstanceOf(KeyException::class, $e);
+            }
+        }
+    }
+
+    #[Test]
+    public function fromPemDirectory(): void
+    {
+        $dir = sys_get_temp_dir() . '/biscuit-keyring-' . bin2hex(random_bytes(4));
+        mkdir($dir);
+
+        try {
+            file_put_contents("{$dir}/4.pem", self::PEM_KEY);
+            file_put_contents("{$dir}/default.pem", self::PEM_KEY);
+            file_put_contents("{$dir}/README.md", 'ignored');
+
+            $ring = KeyRing::fromPemDirectory($dir);
+
+            static::assertSame([4], $ring->ids());
+            static::assertSame(self::PEM_KEY_HEX, $ring->get(4)?->toHex());
+            static::assertSame(self::PEM_KEY_HEX, $ring->getDefault()?->toHex());
+        } finally {
+            array_map('unlink', glob("{$dir}/*") ?: []);
+            rmdir($dir);
+        }
+    }
+
+    #[Test]
+    public function fromPemDirectorySkipsNonPemEntries(): void
+    {
+        $dir = sys_get_temp_dir() . '/biscuit-keyring-' . bin2hex(random_bytes(4));
+        mkdir($dir);
+        mkdir("{$dir}/7.pem");
+
+        try {
+            file_put_contents("{$dir}/3.pem", self::PEM_KEY);
+            file_put_contents("{$dir}/5.txt", 'not a key');
+            file_put_contents("{$dir}/6.pem.bak", 'not a key');
+
+            $ring = KeyRing::fromPemDirectory("{$dir}/");
+
+            static::assertSame([3], $ring->ids());
+            static::assertNull($ring->getDefault());
+        } finally {
+            rmdir("{$dir}/7.pem");
+            array_map('unlink', glob("{$dir}/*") ?: []);
+            rmdir($dir);
+        }
+    }
+
+    #[Test]
+    public function fromPemDirectoryFailsOnInvalidPemFile(): void
+    {
+        $dir = sys_get_temp_dir() . '/biscuit-keyring-' . bin2hex(random_bytes(4));
+        mkdir($dir);
+
+        try {
+            file_put_contents("{$dir}/1.pem", 'not a key');
+
+            $this->expectException(KeyRingException::class);
+
+            KeyRing::fromPemDirectory($dir);
+        } finally {
+            array_map('unlink', glob("{$dir}/*") ?: []);
+            rmdir($dir);
+        }
+    }
+
+    #[Test]
+    public function fromPemDirectoryFailsOnUnreadablePemFile(): void
+    {
+        $dir = sys_get_temp_dir() . '/biscuit-keyring-' . bin2hex(random_bytes(4));
+        mkdir($dir);
+
+        try {
+            file_put_contents("{$dir}/1.pem", self::PEM_KEY);
+            chmod("{$dir}/1.pem", 0);
+            clearstatcache();
+            if (is_readable("{$dir}/1.pem")) {
+                static::markTestSkipped('file permissions are not enforced for this user');
+            }
+
+            $this->expectException(KeyRingException::class);
+            $this->expectExceptionMessage("{$dir}/1.pem");
+
+            KeyRing::fromPemDirectory($dir);
+        } finally {
+            array_map('unlink', glob("{$dir}/*") ?: []);
+            rmdir($dir);
+        }
+    }
+
+    #[Test]
+    public function fromPemDirectoryFailsOnMissingDirectory(): void
+    {
+        $this->expectException(KeyRingException::class);
+
+        KeyRing::fromPemDirectory('/nonexistent/biscuit-keyring');
+    }
+}
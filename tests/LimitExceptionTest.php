@@ -0,0 +1,70 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Biscuit\Tests;
+
+use Biscuit\Auth\AuthorizerBuilder;
+use Biscuit\Auth\Rule;
+use Biscuit\Exception\BiscuitException;
+use Biscuit\Exception\LimitException;
+use PHPUnit\Framework\Attributes\Test;
+use PHPUnit\Framework\TestCase;
+
+class LimitExceptionTest extends TestCase
+{
+    private const CODE = 'a(1); a(2); a(3); b($x) <- a($x); allow if b(3)';
+
+    #[Test]
+    public function limitExceptionExtendsBiscuitException(): void
+    {
+        static::assertTrue(is_subclass_of(LimitException::class, BiscuitException::class));
+    }
+
+    #[Test]
+    public function authorizeWithLimitsSucceedsWithinLimits(): void
+    {
+        $authorizer = (new AuthorizerBuilder(self::CODE))->buildUnauthenticated();
+
+        $policy = $authorizer->authorizeWithLimits(1000, 100, 1_000_000);
+
+        static::assertSame(0, $policy->getPolicyId());
+    }
+
+    #[Test]
+    public function authorizeWithLimitsReportsTooManyFacts(): void
+    {
+        try {
+            $authorizer = (new AuthorizerBuilder(self::CODE))->buildUnauthenticated();
+            $authorizer->authorizeWithLimits(2, 100, 1_000_000);
+            static::fail('Expected LimitException');
+        } catch (LimitException $e) {
+            static::assertSame('max_facts', $e->getLimit());
+        }
+    }
+
+    #[Test]
+    public function builderLimitsApplyToAuthorize(): void
+    {
+        $builder = new AuthorizerBuilder(self::CODE);
+        $builder->setLimits(2, 100, 1_000_000);
+
+        try {
+            $builder->buildUnauthenticated()->authorize();
+            static::fail('Expected LimitException');
+        } catch (LimitException $e) {
+            static::assertSame('max_facts', $e->getLimit());
+        }
+    }
+
+    #[Test]
+    public function builderLimitsApplyToQuery(): void
+    {
+        $builder = new AuthorizerBuilder(self::CODE);
+        $builder->setLimits(2, 100, 1_000_000);
+
+        $this->expectException(LimitException::class);
+
+        $builder->buildUnauthenticated()->query(new Rule('c($x) <- b($x)'));
+    }
+}
@@ -0,0 +1,176 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Biscuit\Tests;
+
+use Biscuit\Auth\AuthorizerBuilder;
+use Biscuit\Auth\Biscuit;
+use Biscuit\Auth\BiscuitBuilder;
+use Biscuit\Auth\Check;
+use Biscuit\Auth\Fact;
+use Biscuit\Auth\KeyPair;
+use Biscuit\Auth\KeyRing;
+use Biscuit\Auth\Policy;
+use Biscuit\Auth\PrivateKey;
+use Biscuit\Auth\PublicKey;
+use Biscuit\Auth\Rule;
+use Biscuit\Auth\UnverifiedBiscuit;
+use Biscuit\Exception\BytesException;
+use Biscuit\Exception\FactException;
+use Biscuit\Exception\KeyRingException;
+use Biscuit\Exception\PrivateKeyException;
+use Exception;
+use PHPUnit\Framework\TestCase;
+
+class SerializationTest extends TestCase
+{
+    protected function tearDown(): void
+    {
+        Biscuit::setUnserializeRootKey(null);
+        PrivateKey::allowSerialization(false);
+    }
+
+    public function testBiscuitRoundTrip(): void
+    {
+        $root = new KeyPair();
+        $token = (new BiscuitBuilder('user("alice")'))->build($root->getPrivateKey());
+
+        Biscuit::setUnserializeRootKey($root->getPublicKey());
+        $restored = unserialize(serialize($token));
+
+        static::assertInstanceOf(Biscuit::class, $restored);
+        static::assertSame($token->toBase64(), $restored->toBase64());
+        static::assertSame($token->revocationIds(), $restored->revocationIds());
+    }
+
+    public function testBiscuitUnserializeWithKeyRing(): void
+    {
+        $root = new KeyPair();
+        $builder = new BiscuitBuilder('user("alice")');
+        $builder->setRootKeyId(7);
+        $token = $builder->build($root->getPrivateKey());
+
+        $ring = new KeyRing();
+        $ring->add(7, $root->getPublicKey());
+        Biscuit::setUnserializeRootKey($ring);
+
+        static::assertSame($token->toBase64(), unserialize(serialize($token))->toBase64());
+    }
+
+    public function testBiscuitUnserializeWithPublicKeyIgnoresRootKeyId(): void
+    {
+        $root = new KeyPair();
+        $builder = new BiscuitBuilder('user("alice")');
+        $builder->setRootKeyId(7);
+        $token = $builder->build($root->getPrivateKey());
+
+        Biscuit::setUnserializeRootKey($root->getPublicKey());
+
+        static::assertSame($token->toBase64(), unserialize(serialize($token))->toBase64());
+    }
+
+    public function testClassesWithoutSerializeHooksStayUnserializable(): void
+    {
+        $this->expectException(Exception::class);
+        $this->expectExceptionMessage("Serialization of 'Biscuit\\Auth\\KeyPair' is not allowed");
+
+        serialize(new KeyPair());
+    }
+
+    public function testBiscuitUnserializeWithoutRootKeyThrows(): void
+    {
+        $token = (new BiscuitBuilder('user("alice")'))->build((new KeyPair())->getPrivateKey());
+        $serialized = serialize($token);
+
+        $this->expectException(KeyRingException::class);
+
+        unserialize($serialized);
+    }
+
+    public function testBiscuitUnserializeWithWrongRootKeyThrows(): void
+    {
+        $token = (new BiscuitBuilder('user("alice")'))->build((new KeyPair())->getPrivateKey());
+        $serialized = serialize($token);
+
+        Biscuit::setUnserializeRootKey((new KeyPair())->getPublicKey());
+
+        $this->expectException(BytesException::class);
+
+        unserialize($serialized);
+    }
+
+    public function testUnverifiedBiscuitRoundTrip(): void
+    {
+        $token = (new BiscuitBuilder('user("alice")'))->build((new KeyPair())->getPrivateKey());
+        $unverified = UnverifiedBiscuit::fromBase64($token->toBase64());
+
+        $restored = unserialize(serialize($unverified));
+
+        static::assertInstanceOf(UnverifiedBiscuit::class, $restored);
+        static::assertSame($unverified->toBase64(), $restored->toBase64());
+    }
+
+    public function testPublicKeyRoundTrip(): void
+    {
+        $publicKey = (new KeyPair())->getPublicKey();
+
+        $restored = unserialize(serialize($publicKey));
+
+        static::assertInstanceOf(PublicKey::class, $restored);
+        static::assertTrue($publicKey->equals($restored));
+    }
+
+    public function testPrivateKeySerializationIsOptIn(): void
+    {
+        $privateKey = PrivateKey::generate();
+
+        try {
+            serialize($privateKey);
+            static::fail('Expected PrivateKeyException');
+        } catch (PrivateKeyException $e) {
+            static::assertStringContainsString('allowSerialization', $e->getMessage());
+        }
+
+        PrivateKey::allowSerialization(true);
+        $restored = unserialize(serialize($privateKey));
+
+        static::assertSame($privateKey->exportSecret(), $restored->exportSecret());
+    }
+
+    public function testDatalogRoundTrip(): void
+    {
+        $values = [
+            new Fact('user({id})', ['id' => 'alice']),
+            new Rule('right($r) <- resource($r), owner({user}, $r)', ['user' => 'alice']),
+            new Check('check if time($t), $t < {limit}', ['limit' => 1000]),
+            new Policy('allow if user("alice")'),
+        ];
+
+        foreach ($values as $value) {
+            $restored = unserialize(serialize($value));
+
+            static::assertInstanceOf($value::class, $restored);
+            static::assertSame((string) $value, (string) $restored);
+        }
+    }
+
+    public function testMalformedDatalogPayloadThrows(): void
+    {
+        $this->expectException(FactException::class);
+
+        unserialize('O:17:"Biscuit\Auth\Fact":1:{s:6:"source";s:7:"user(((";}');
+    }
+
+    public function testAuthorizerBuilderRoundTrip(): void
+    {
+        $root = new KeyPair();
+        $token = (new BiscuitBuilder('user("alice")'))->build($root->getPrivateKey());
+
+        $builder = new AuthorizerBuilder('allow if user("alice")');
+        $restored = unserialize(serialize($builder));
+
+        static::assertInstanceOf(AuthorizerBuilder::class, $restored);
+        static::assertSame(0, $restored->build($token)->authorize()->getPolicyId());
+    }
+}
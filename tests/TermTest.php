@@ -0,0 +1,78 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Biscuit\Tests;
+
+use Biscuit\Auth\Fact;
+use Biscuit\Auth\Term;
+use DateTimeImmutable;
+use PHPUnit\Framework\Attributes\Test;
+use PHPUnit\Framework\TestCase;
+
+class TermTest extends TestCase
+{
+    #[Test]
+    public function scalarConstructors(): void
+    {
+        static::assertSame('"alice"', (string) Term::string('alice'));
+        static::assertSame('hex:deadbeef', (string) Term::bytes(hex2bin('deadbeef')));
+        static::assertSame('42', (string) Term::integer(42));
+        static::assertSame('true', (string) Term::bool(true));
+        static::assertSame('null', (string) Term::null());
+    }
+
+    #[Test]
+    public function dateConstructor(): void
+    {
+        $term = Term::date(new DateTimeImmutable('2024-01-01T00:00:00Z'));
+
+        static::assertSame('date', $term->getType());
+        static::assertSame('2024-01-01T00:00:00Z', (string) $term);
+    }
+
+    #[Test]
+    public function collectionConstructors(): void
+    {
+        static::assertSame('set', Term::set([2, 1, 2])->getType());
+        static::assertSame([1, 2], Term::set([2, 1, 2])->getValue());
+
+        static::assertSame('array', Term::array([2, 1, 2])->getType());
+        static::assertSame([2, 1, 2], Term::array([2, 1, 2])->getValue());
+
+        static::assertSame('map', Term::map(['a' => 1, 2 => 'b'])->getType());
+    }
+
+    #[Test]
+    public function termDisambiguatesBytesFromStrings(): void
+    {
+        $asString = new Fact('data({v})', ['v' => 'ab']);
+        $asBytes = new Fact('data({v})', ['v' => Term::bytes('ab')]);
+
+        static::assertSame('data("ab")', (string) $asString);
+        static::assertSame('data(hex:6162)', (string) $asBytes);
+    }
+
+    #[Test]
+    public function termBindsNull(): void
+    {
+        $fact = new Fact('data({v})');
+        $fact->set('v', Term::null());
+
+        static::assertSame('data(null)', (string) $fact);
+    }
+
+    #[Test]
+    public function getTypedTermsRoundTrips(): void
+    {
+        $fact = new Fact('data(1, "a", hex:00)');
+
+        $terms = $fact->getTypedTerms();
+
+        $types = array_map(static fn(Term $t): string => $t->getType(), $terms);
+        static::assertSame(['integer', 'string', 'bytes'], $types);
+
+        $copy = new Fact('data({a}, {b}, {c})', ['a' => $terms[0], 'b' => $terms[1], 'c' => $terms[2]]);
+        static::assertSame((string) $fact, (string) $copy);
+    }
+}